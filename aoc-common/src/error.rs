@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+pub type Result<T, E = anyhow::Error> = std::result::Result<T, E>;
+
+#[derive(Error, Debug)]
+pub enum AocError {
+    #[error("could not read input `{path}`")]
+    Input {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error(transparent)]
+    ParseInt(#[from] std::num::ParseIntError),
+
+    #[error("day {day} part {part} is not solved yet")]
+    Unsolved { day: u8, part: u8 },
+
+    #[error("there is no part {0}, puzzles only have parts 1 and 2")]
+    NoSuchPart(u8),
+
+    #[error("parse error on line {line}: {message}")]
+    Parse { line: usize, message: String },
+}
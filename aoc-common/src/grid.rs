@@ -0,0 +1,271 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut, Range},
+    str::FromStr,
+};
+
+use crate::AocError;
+
+/// A cell position, zero-based from the top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+
+    /// Moves by `(rows, cols)`, or `None` when that would leave the first
+    /// quadrant. Does not know about the grid's far edges.
+    pub fn offset(self, rows: isize, cols: isize) -> Option<Pos> {
+        Some(Pos {
+            row: self.row.checked_add_signed(rows)?,
+            col: self.col.checked_add_signed(cols)?,
+        })
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.row + 1, self.col + 1)
+    }
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from equally long rows.
+    pub fn from_rows<R>(rows: impl IntoIterator<Item = R>) -> Result<Self, AocError>
+    where
+        R: IntoIterator<Item = T>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for row in rows {
+            let before = cells.len();
+            cells.extend(row);
+            let len = cells.len() - before;
+
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(AocError::Parse {
+                        line: height + 1,
+                        message: format!("row has {} cells, expected {}", len, width),
+                    })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.row * self.width + pos.col])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on zero, an empty grid simply has no rows
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| (Pos::new(index / width, index % width), cell))
+    }
+
+    /// The up to four cells sharing an edge with `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.around(pos, &ORTHOGONAL)
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `pos`.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.around(pos, &SURROUNDING)
+    }
+
+    /// The cells surrounding the horizontal run `cols` on `row`, diagonals
+    /// included, clipped to the grid. The run itself is not part of it.
+    pub fn span_neighbours(
+        &self,
+        row: usize,
+        cols: Range<usize>,
+    ) -> impl Iterator<Item = Pos> + '_ {
+        let top = row.checked_sub(1);
+        let bottom = Some(row + 1).filter(|&row| row < self.height);
+        let left = cols.start.checked_sub(1);
+        let right = Some(cols.end).filter(|&col| col < self.width);
+        let wide =
+            left.unwrap_or(cols.start)..right.map_or(cols.end, |col| col + 1).min(self.width);
+
+        let edges = [top, bottom]
+            .into_iter()
+            .flatten()
+            .flat_map(move |row| wide.clone().map(move |col| Pos::new(row, col)));
+        let sides = [left, right]
+            .into_iter()
+            .flatten()
+            .map(move |col| Pos::new(row, col));
+
+        edges.chain(sides).filter(move |&pos| self.contains(pos))
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.into_iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn around<'a>(
+        &'a self,
+        pos: Pos,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&(rows, cols)| pos.offset(rows, cols))
+            .filter(|&pos| self.contains(pos))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        &self.cells[pos.row * self.width + pos.col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        &mut self.cells[pos.row * self.width + pos.col]
+    }
+}
+
+/// One cell per character, one row per line. Trailing blank lines are
+/// ignored, a blank line anywhere else is an error.
+impl FromStr for Grid<char> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, AocError> {
+        let lines = s.trim_end_matches(['\n', '\r']).lines();
+
+        if let Some(index) = lines.clone().position(str::is_empty) {
+            return Err(AocError::Parse {
+                line: index + 1,
+                message: "blank line inside the grid".to_string(),
+            });
+        }
+
+        Grid::from_rows(lines.map(str::chars))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid: Grid<char> = "abc\ndef\nghi\n".parse().unwrap();
+
+        assert_eq!(grid.neighbours8(Pos::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours4(Pos::new(2, 2))
+                .map(|pos| grid[pos])
+                .collect::<String>(),
+            "fh"
+        );
+    }
+
+    #[test]
+    fn span_neighbours_are_clipped() {
+        let grid: Grid<char> = "12.\n...\n".parse().unwrap();
+
+        let mut around = grid.span_neighbours(0, 0..2).collect::<Vec<_>>();
+        around.sort();
+        assert_eq!(
+            around,
+            [(0, 2), (1, 0), (1, 1), (1, 2)].map(|(row, col)| Pos::new(row, col))
+        );
+        assert!(grid
+            .span_neighbours(1, 1..5)
+            .chain(grid.span_neighbours(2, 0..1))
+            .all(|pos| grid.contains(pos)));
+    }
+
+    #[test]
+    fn ragged_rows_are_rejected() {
+        assert!("abc\nde\n".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn only_trailing_blank_lines_are_ignored() {
+        assert_eq!("ab\ncd\n\n".parse::<Grid<char>>().unwrap().height(), 2);
+        assert!("ab\n\ncd\n".parse::<Grid<char>>().is_err());
+    }
+}
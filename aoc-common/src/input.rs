@@ -0,0 +1,119 @@
+use std::{
+    env, fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{AocError, Result};
+
+/// Environment variable naming the input file, or `-` for stdin.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Picks where a day reads its puzzle from.
+    ///
+    /// An explicit argument wins over `AOC_INPUT`, which wins over the
+    /// crate's own `data/data.txt`. `-` means stdin in either place.
+    pub fn resolve(arg: Option<&str>, crate_dir: impl AsRef<Path>) -> Self {
+        let env = env::var(INPUT_ENV).ok().filter(|value| !value.is_empty());
+
+        match arg.or(env.as_deref()) {
+            Some(value) => value.into(),
+            None => InputSource::File(default_path(crate_dir)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::File(path) => {
+                fs::read_to_string(path).map_err(|source| input_error(path.display(), source))
+            }
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| input_error("<stdin>", source))?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Opens the input for streaming instead of reading it all up front.
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::File(path) => {
+                let file =
+                    fs::File::open(path).map_err(|source| input_error(path.display(), source))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(value: &str) -> Self {
+        match value {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+}
+
+fn default_path(crate_dir: impl AsRef<Path>) -> PathBuf {
+    crate_dir.as_ref().join("data").join("data.txt")
+}
+
+fn input_error(path: impl ToString, source: io::Error) -> anyhow::Error {
+    AocError::Input {
+        path: path.to_string(),
+        source,
+    }
+    .into()
+}
+
+/// Reads `data/data.txt` below the given crate directory.
+pub fn read_input(crate_dir: impl AsRef<Path>) -> Result<String> {
+    InputSource::File(default_path(crate_dir)).read()
+}
+
+/// Loads the calling day's input from its first command line argument,
+/// `AOC_INPUT`, or its `data/data.txt`, in that order.
+#[macro_export]
+macro_rules! load_input {
+    () => {
+        $crate::InputSource::resolve(
+            ::std::env::args().nth(1).as_deref(),
+            env!("CARGO_MANIFEST_DIR"),
+        )
+        .read()
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dash_means_stdin() {
+        assert_eq!(InputSource::from("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from("example.txt"),
+            InputSource::File(PathBuf::from("example.txt"))
+        );
+    }
+
+    #[test]
+    fn argument_wins() {
+        assert_eq!(
+            InputSource::resolve(Some("example.txt"), "day-1/trebuchet"),
+            InputSource::File(PathBuf::from("example.txt"))
+        );
+    }
+}
@@ -0,0 +1,15 @@
+pub mod answer;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod solver;
+
+pub use answer::Answer;
+pub use error::{AocError, Result};
+pub use grid::{Grid, Pos};
+pub use input::{read_input, InputSource};
+pub use output::print_answer;
+pub use solver::Solver;
+
+pub use anyhow::{anyhow, bail, Context};
@@ -0,0 +1,5 @@
+use std::fmt::Display;
+
+pub fn print_answer(part: u8, answer: impl Display) {
+    println!("Part {}: {}", part, answer);
+}
@@ -0,0 +1,48 @@
+use crate::{Answer, AocError, InputSource, Result};
+
+/// A day's puzzle, as seen by the `aoc` runner.
+///
+/// Only `day` and `crate_dir` are required; parts a day has not solved yet
+/// report [`AocError::Unsolved`].
+pub trait Solver {
+    fn day(&self) -> u8;
+
+    /// Directory of the crate holding this day, used to find its `data/`.
+    fn crate_dir(&self) -> &'static str;
+
+    /// Parses the input without solving it and returns how many records it
+    /// holds, so parsing can be timed on its own. `None` for days that only
+    /// parse inline.
+    fn parse(&self, _input: &str) -> Option<Result<usize>> {
+        None
+    }
+
+    fn part1(&self, _input: &str) -> Result<Answer> {
+        Err(AocError::Unsolved {
+            day: self.day(),
+            part: 1,
+        }
+        .into())
+    }
+
+    fn part2(&self, _input: &str) -> Result<Answer> {
+        Err(AocError::Unsolved {
+            day: self.day(),
+            part: 2,
+        }
+        .into())
+    }
+
+    fn solve(&self, part: u8, input: &str) -> Result<Answer> {
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            part => Err(AocError::NoSuchPart(part).into()),
+        }
+    }
+
+    /// Reads the puzzle input, see [`InputSource::resolve`].
+    fn input(&self, arg: Option<&str>) -> Result<String> {
+        InputSource::resolve(arg, self.crate_dir()).read()
+    }
+}
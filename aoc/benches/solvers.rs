@@ -0,0 +1,62 @@
+//! Times parsing and both parts of every registered day against its real
+//! input. Days without a `data/data.txt` are skipped.
+//!
+//! Record a baseline before a rewrite and compare against it afterwards:
+//!
+//! ```sh
+//! cargo bench -p aoc --bench solvers -- --save-baseline before
+//! cargo bench -p aoc --bench solvers -- --baseline before
+//! ```
+//!
+//! The numbers the current code started from are kept in `baseline.md`.
+
+use std::time::Duration;
+
+use aoc_common::{read_input, Solver};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_day(c: &mut Criterion, solver: &dyn Solver) {
+    // always the day's own input, `AOC_INPUT` would be the same for all
+    let input = match read_input(solver.crate_dir()) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("day {}: skipping, {:#}", solver.day(), err);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day-{:02}", solver.day()));
+
+    if solver.parse(&input).is_some() {
+        group.bench_function("parse", |b| b.iter(|| solver.parse(black_box(&input))));
+    }
+
+    for part in [1, 2] {
+        if let Err(err) = solver.solve(part, &input) {
+            eprintln!("day {} part {}: skipping, {:#}", solver.day(), part, err);
+            continue;
+        }
+
+        group.bench_function(format!("part-{}", part), |b| {
+            b.iter(|| solver.solve(part, black_box(&input)))
+        });
+    }
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    for solver in aoc::solvers() {
+        bench_day(c, solver.as_ref());
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(500))
+        .measurement_time(Duration::from_secs(2));
+    targets = solvers
+}
+criterion_main!(benches);
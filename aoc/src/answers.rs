@@ -0,0 +1,110 @@
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{Answer, Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Known-good answers, keyed by day, part and a hash of the input so that
+/// several people's puzzle inputs can share one file.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct AnswerStore {
+    #[serde(default, rename = "answer")]
+    answers: Vec<RecordedAnswer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RecordedAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+impl AnswerStore {
+    /// `answers.toml` at the workspace root.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("answers.toml")
+    }
+
+    /// Loads the store, treating a missing file as an empty one.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .with_context(|| format!("could not parse `{}`", path.display())),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("could not read `{}`", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+
+        fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("could not write `{}`", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_hash: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|recorded| {
+                recorded.day == day && recorded.part == part && recorded.input == input_hash
+            })
+            .map(|recorded| recorded.answer.as_str())
+    }
+
+    /// Records an answer, replacing any previous one for the same input.
+    pub fn record(&mut self, day: u8, part: u8, input_hash: &str, answer: &Answer) {
+        self.answers.retain(|recorded| {
+            !(recorded.day == day && recorded.part == part && recorded.input == input_hash)
+        });
+        self.answers.push(RecordedAnswer {
+            day,
+            part,
+            input: input_hash.to_string(),
+            answer: answer.to_string(),
+        });
+        self.answers
+            .sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    }
+}
+
+/// FNV-1a over the input with surrounding whitespace trimmed, so a trailing
+/// newline added by an editor does not orphan a recorded answer.
+pub fn input_hash(input: &str) -> String {
+    let hash = input
+        .trim()
+        .bytes()
+        .fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hash_ignores_trailing_newline() {
+        assert_eq!(input_hash("1abc2\n"), input_hash("1abc2"));
+        assert_ne!(input_hash("1abc2"), input_hash("1abc3"));
+    }
+
+    #[test]
+    fn record_replaces_previous_answer() {
+        let mut store = AnswerStore::default();
+        store.record(1, 2, "abc", &Answer::from(1));
+        store.record(1, 2, "abc", &Answer::from(2));
+
+        assert_eq!(store.get(1, 2, "abc"), Some("2"));
+        assert_eq!(store.get(1, 1, "abc"), None);
+    }
+}
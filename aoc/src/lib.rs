@@ -0,0 +1,21 @@
+pub mod answers;
+pub mod verify;
+
+use aoc_common::Solver;
+
+pub fn solvers() -> Vec<Box<dyn Solver>> {
+    vec![
+        Box::new(trebuchet::Solution),
+        Box::new(cube_coundrum::Solution),
+        Box::new(gear_ratios::Solution),
+        Box::new(scrachcards::Solution),
+        Box::new(wait_for_it::Solution),
+        Box::new(camel_cards::Solution),
+        Box::new(mirage_maintenance::Solution),
+        Box::new(day_15::Solution),
+    ]
+}
+
+pub fn solver(day: u8) -> Option<Box<dyn Solver>> {
+    solvers().into_iter().find(|solver| solver.day() == day)
+}
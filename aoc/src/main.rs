@@ -0,0 +1,153 @@
+use std::path::PathBuf;
+
+use aoc::{
+    answers::AnswerStore,
+    verify::{verify, Status},
+};
+use aoc_common::{bail, print_answer, read_input, AocError, Result};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day's solver and print its answers
+    Run {
+        #[arg(long)]
+        day: u8,
+
+        /// Only run this part; both parts run when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file, or `-` for stdin; defaults to `$AOC_INPUT`, then the
+        /// day's `data/data.txt`
+        #[arg(long)]
+        input: Option<String>,
+    },
+
+    /// Re-run every solver and compare against the recorded answers
+    ///
+    /// Each day reads its own `data/data.txt`, `$AOC_INPUT` is ignored.
+    Verify {
+        /// Only verify this day
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Answers file; defaults to `answers.toml` at the workspace root
+        #[arg(long)]
+        answers: Option<PathBuf>,
+
+        /// Record the answers of inputs that have none yet
+        #[arg(long)]
+        record: bool,
+    },
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run { day, part, input } => run(day, part, input.as_deref()),
+        Command::Verify {
+            day,
+            answers,
+            record,
+        } => verify_all(
+            day,
+            answers.unwrap_or_else(AnswerStore::default_path),
+            record,
+        ),
+    }
+}
+
+fn run(day: u8, part: Option<u8>, input: Option<&str>) -> Result<()> {
+    let Some(solver) = aoc::solver(day) else {
+        bail!("day {} has no registered solver", day);
+    };
+
+    let input = solver.input(input)?;
+
+    if let Some(part) = part {
+        print_answer(part, solver.solve(part, &input)?);
+        return Ok(());
+    }
+
+    for part in [1, 2] {
+        match solver.solve(part, &input) {
+            Ok(answer) => print_answer(part, answer),
+            Err(err) if matches!(err.downcast_ref(), Some(AocError::Unsolved { .. })) => {
+                eprintln!("Part {}: {}", part, err)
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(())
+}
+
+fn verify_all(day: Option<u8>, answers: PathBuf, record: bool) -> Result<()> {
+    let mut store = AnswerStore::load(&answers)?;
+    let (mut failed, mut regressed, mut recorded) = (0, 0, 0);
+
+    for solver in aoc::solvers() {
+        if day.is_some_and(|day| day != solver.day()) {
+            continue;
+        }
+
+        // not `solver.input`, `AOC_INPUT` would feed one file to every day
+        let input = match read_input(solver.crate_dir()) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("day {:>2}: FAIL {:#}", solver.day(), err);
+                failed += 1;
+                continue;
+            }
+        };
+
+        for outcome in verify(solver.as_ref(), &input, &store) {
+            let label = format!("day {:>2} part {}", outcome.day, outcome.part);
+
+            match &outcome.status {
+                Status::Pass => println!("{}: pass", label),
+                Status::Unsolved => println!("{}: unsolved", label),
+                Status::Regression { expected } => {
+                    regressed += 1;
+                    println!(
+                        "{}: REGRESSION expected {}, got {}",
+                        label,
+                        expected,
+                        outcome.answer.as_ref().unwrap()
+                    );
+                }
+                Status::Fail(message) => {
+                    failed += 1;
+                    println!("{}: FAIL {}", label, message);
+                }
+                Status::Unrecorded => {
+                    let answer = outcome.answer.as_ref().unwrap();
+                    if record {
+                        store.record(outcome.day, outcome.part, &outcome.input_hash, answer);
+                        recorded += 1;
+                        println!("{}: recorded {}", label, answer);
+                    } else {
+                        println!("{}: unrecorded {}", label, answer);
+                    }
+                }
+            }
+        }
+    }
+
+    if recorded > 0 {
+        store.save(&answers)?;
+    }
+
+    if failed + regressed > 0 {
+        bail!("{} failed, {} regressed", failed, regressed);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use aoc_common::{Answer, AocError, Solver};
+
+use crate::answers::{input_hash, AnswerStore};
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    /// Matches the recorded answer.
+    Pass,
+    /// Differs from the recorded answer.
+    Regression {
+        expected: String,
+    },
+    /// Produced an answer, but none is recorded for this input yet.
+    Unrecorded,
+    Unsolved,
+    /// The solver returned an error or panicked.
+    Fail(String),
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: Option<Answer>,
+    pub status: Status,
+}
+
+/// Runs both parts of a day on `input` and checks them against the store.
+pub fn verify(solver: &dyn Solver, input: &str, store: &AnswerStore) -> Vec<Outcome> {
+    let hash = input_hash(input);
+
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| solver.solve(part, input)));
+
+            let (answer, status) = match result {
+                Ok(Ok(answer)) => {
+                    let status = match store.get(solver.day(), part, &hash) {
+                        Some(expected) if expected == answer.to_string() => Status::Pass,
+                        Some(expected) => Status::Regression {
+                            expected: expected.to_string(),
+                        },
+                        None => Status::Unrecorded,
+                    };
+                    (Some(answer), status)
+                }
+                Ok(Err(err)) if matches!(err.downcast_ref(), Some(AocError::Unsolved { .. })) => {
+                    (None, Status::Unsolved)
+                }
+                Ok(Err(err)) => (None, Status::Fail(format!("{:#}", err))),
+                Err(_) => (None, Status::Fail("solver panicked".to_string())),
+            };
+
+            Outcome {
+                day: solver.day(),
+                part,
+                input_hash: hash.clone(),
+                answer,
+                status,
+            }
+        })
+        .collect()
+}
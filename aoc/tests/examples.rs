@@ -0,0 +1,52 @@
+use std::{fs, path::Path};
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Examples {
+    example: Vec<Example>,
+}
+
+#[derive(Deserialize)]
+struct Example {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: String,
+}
+
+#[test]
+fn examples_match_recorded_answers() {
+    let examples: Examples = toml::from_str(include_str!("examples.toml")).unwrap();
+
+    let failures = examples
+        .example
+        .iter()
+        .filter_map(|example| {
+            let label = format!("day {} part {}", example.day, example.part);
+
+            let Some(solver) = aoc::solver(example.day) else {
+                return Some(format!("{}: no registered solver", label));
+            };
+
+            let path = Path::new(solver.crate_dir())
+                .join("data")
+                .join(&example.input);
+            let input = match fs::read_to_string(&path) {
+                Ok(input) => input,
+                Err(err) => return Some(format!("{}: {}: {}", label, path.display(), err)),
+            };
+
+            match solver.solve(example.part, &input) {
+                Ok(answer) if answer.to_string() == example.answer => None,
+                Ok(answer) => Some(format!(
+                    "{}: expected {}, got {}",
+                    label, example.answer, answer
+                )),
+                Err(err) => Some(format!("{}: {:#}", label, err)),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,38 @@
+//! Prints how every line was calibrated.
+//!
+//! Usage: `breakdown [input] [vocabulary] [--ignore-case] [--reversed]`
+//!
+//! The vocabulary is `digits`, a built-in language (`english`, `french`,
+//! `german`) or the path of a word list, English by default.
+
+use std::env;
+
+use aoc_common::{bail, load_input, Result};
+use trebuchet::{breakdown, Vocabulary};
+
+fn main() -> Result<()> {
+    let data = load_input!()?;
+
+    let mut vocabulary = match env::args().nth(2).as_deref() {
+        None => Vocabulary::english(),
+        Some("digits") => Vocabulary::new(),
+        Some(name) => match Vocabulary::named(name) {
+            Some(vocabulary) => vocabulary,
+            None => Vocabulary::load(name)?,
+        },
+    };
+
+    for flag in env::args().skip(3) {
+        vocabulary = match flag.as_str() {
+            "--ignore-case" => vocabulary.ignore_case(),
+            "--reversed" => vocabulary.with_reversed(),
+            other => bail!("unknown option `{}`", other),
+        };
+    }
+
+    for report in breakdown(&data, vocabulary)? {
+        println!("{}", report);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,281 @@
+use std::{
+    fmt,
+    io::{BufRead, BufReader, Read},
+    ops::Range,
+};
+
+use aoc_common::{anyhow, Answer, AocError, Result, Solver};
+
+mod scanner;
+mod vocabulary;
+
+pub use scanner::{Match, Rule, Scanner};
+pub use vocabulary::Vocabulary;
+
+/// A digit and the bytes of its line it was read from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Found {
+    pub digit: u8,
+    pub span: Range<usize>,
+}
+
+/// How one line was calibrated, for checking disputed lines by hand.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LineReport {
+    /// 1-based line number.
+    pub line: usize,
+    pub first: Option<Found>,
+    pub last: Option<Found>,
+    /// Nothing but whitespace on the line.
+    pub blank: bool,
+}
+
+impl LineReport {
+    /// The calibration value, `None` for a line without digits.
+    pub fn value(&self) -> Option<u64> {
+        let (first, last) = (self.first.as_ref()?, self.last.as_ref()?);
+        Some(u64::from(first.digit * 10 + last.digit))
+    }
+
+    fn see(&mut self, found: Found) {
+        if self.first.is_none() {
+            self.first = Some(found.clone());
+        }
+        self.last = Some(found);
+    }
+}
+
+impl fmt::Display for LineReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.first, &self.last) {
+            (Some(first), Some(last)) => write!(
+                f,
+                "line {}: first {} at {:?}, last {} at {:?} = {}",
+                self.line,
+                first.digit,
+                first.span,
+                last.digit,
+                last.span,
+                first.digit * 10 + last.digit
+            ),
+            _ => write!(f, "line {}: no digits", self.line),
+        }
+    }
+}
+
+/// Scans `reader` one chunk at a time, so memory use does not grow with the
+/// input, and hands every finished line to `on_line`.
+///
+/// `vocabulary` is either a [`Rule`] or a [`Vocabulary`] of its own.
+pub fn scan(
+    reader: impl Read,
+    vocabulary: impl Into<Vocabulary>,
+    mut on_line: impl FnMut(LineReport),
+) -> Result<()> {
+    let mut reader = BufReader::new(reader);
+    let mut scanner = Scanner::new(vocabulary);
+    let mut report = LineReport {
+        line: 1,
+        ..Default::default()
+    };
+    let mut column = 0;
+    let mut blank = true;
+
+    loop {
+        let chunk = reader.fill_buf()?;
+        if chunk.is_empty() {
+            break;
+        }
+
+        for &byte in chunk {
+            if byte == b'\n' {
+                let line = report.line;
+                report.blank = blank;
+                on_line(std::mem::take(&mut report));
+                report.line = line + 1;
+                column = 0;
+                blank = true;
+                scanner.reset();
+                continue;
+            }
+
+            column += 1;
+            blank &= byte.is_ascii_whitespace();
+            if let Some(found) = scanner.push(byte) {
+                report.see(Found {
+                    digit: found.digit,
+                    span: column - found.len..column,
+                });
+            }
+        }
+
+        let len = chunk.len();
+        reader.consume(len);
+    }
+
+    if column > 0 {
+        report.blank = blank;
+        on_line(report);
+    }
+
+    Ok(())
+}
+
+/// A line that has no calibration value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// 1-based line number.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Calibration {
+    pub total: u64,
+    /// Lines left out of `total`.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Sums the calibration values of everything `reader` yields.
+///
+/// Blank lines are ignored. Other lines without any digit are skipped and
+/// reported, or fail the whole run when `strict` is set. A total that does
+/// not fit a `u64` is an error.
+pub fn calibrate(
+    reader: impl Read,
+    vocabulary: impl Into<Vocabulary>,
+    strict: bool,
+) -> Result<Calibration> {
+    let mut calibration = Calibration::default();
+    let mut error = None;
+
+    scan(reader, vocabulary, |report| {
+        if error.is_some() || report.blank {
+            return;
+        }
+
+        match report.value() {
+            Some(value) => match calibration.total.checked_add(value) {
+                Some(total) => calibration.total = total,
+                None => {
+                    error = Some(anyhow!(
+                        "calibration total overflows on line {}",
+                        report.line
+                    ))
+                }
+            },
+            None if strict => {
+                error = Some(
+                    AocError::Parse {
+                        line: report.line,
+                        message: "no digits".to_string(),
+                    }
+                    .into(),
+                )
+            }
+            None => calibration.diagnostics.push(Diagnostic {
+                line: report.line,
+                message: "no digits, line skipped".to_string(),
+            }),
+        }
+    })?;
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(calibration),
+    }
+}
+
+/// Sums the calibration values of everything `reader` yields, skipping
+/// lines without digits.
+pub fn sum_calibration(reader: impl Read, vocabulary: impl Into<Vocabulary>) -> Result<u64> {
+    Ok(calibrate(reader, vocabulary, false)?.total)
+}
+
+/// Every line of `data` with the digits that make up its value.
+pub fn breakdown(data: &str, vocabulary: impl Into<Vocabulary>) -> Result<Vec<LineReport>> {
+    let mut reports = Vec::new();
+    scan(data.as_bytes(), vocabulary, |report| reports.push(report))?;
+
+    Ok(reports)
+}
+
+pub fn part1(data: &str) -> Result<Answer> {
+    Ok(sum_calibration(data.as_bytes(), Rule::Digits)?.into())
+}
+
+pub fn part2(data: &str) -> Result<Answer> {
+    Ok(sum_calibration(data.as_bytes(), Rule::Spelled)?.into())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn crate_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn breakdown_points_at_the_digits() {
+        let reports = breakdown("xtwone3four\nabc\n", Rule::Spelled).unwrap();
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(
+            reports[0].first,
+            Some(Found {
+                digit: 2,
+                span: 1..4
+            })
+        );
+        assert_eq!(
+            reports[0].last,
+            Some(Found {
+                digit: 4,
+                span: 7..11
+            })
+        );
+        assert_eq!(reports[0].value(), Some(24));
+        assert_eq!(reports[1].value(), None);
+    }
+
+    #[test]
+    fn lines_without_digits() {
+        let data = "1abc2\nnothing here\n\n  \n3\n\n";
+
+        let calibration = calibrate(data.as_bytes(), Rule::Digits, false).unwrap();
+        assert_eq!(calibration.total, 45);
+        assert_eq!(
+            calibration.diagnostics,
+            [Diagnostic {
+                line: 2,
+                message: "no digits, line skipped".to_string()
+            }]
+        );
+
+        let error = calibrate(data.as_bytes(), Rule::Digits, true).unwrap_err();
+        assert_eq!(error.to_string(), "parse error on line 2: no digits");
+    }
+}
@@ -1,65 +1,36 @@
-use std::{collections::HashMap, fs, ops::Index};
-
-fn main() {
-    let data = fs::read_to_string("data/data.txt").unwrap();
-
-    let numbers_to_strings = HashMap::from([
-        ("one", '1'),
-        ("two", '2'),
-        ("three", '3'),
-        ("four", '4'),
-        ("five", '5'),
-        ("six", '6'),
-        ("seven", '7'),
-        ("eight", '8'),
-        ("nine", '9'),
-    ]);
-
-    let total = data
-        .trim()
-        .split("\n")
-        .into_iter()
-        .enumerate()
-        .map(|(index, mut i)| {
-            let mut sum: Vec<char> = vec![];
-
-            let mut matches: Vec<(usize, char)> = vec![];
-
-            numbers_to_strings.keys().for_each(|number| {
-                let mut j = i.to_string();
-                while let Some(index) = j.find(number) {
-                    matches.push((
-                        j.find(number).unwrap(),
-                        numbers_to_strings.get(number).unwrap().to_owned(),
-                    ));
-                    j.replace_range(
-                        index..index + number.len(),
-                        " ".repeat(number.len()).as_str(),
-                    );
-                }
-            });
-
-            let numbers: Vec<(usize, char)> = i
-                .chars()
-                .enumerate()
-                .filter(|(index, x)| x.is_numeric())
-                .map(|(x, c)| (x, c))
-                .collect();
-
-            matches.extend(numbers);
-
-            matches.sort();
-
-            if let Some(&first) = matches.first() {
-                sum.push(first.1);
-            }
-
-            if let Some(&last) = matches.last() {
-                sum.push(last.1);
-            }
-            sum.iter().collect::<String>().parse::<i32>().unwrap_or(0)
-        })
-        .fold(0, |total, x| total + x);
-
-    println!("Total: {:?}", total);
+//! Usage: `trebuchet [input] [--strict]`
+//!
+//! Lines without digits are reported on stderr and left out, `--strict`
+//! makes them fail the run instead.
+
+use std::{env, io::Read};
+
+use aoc_common::{print_answer, InputSource, Result};
+use trebuchet::{calibrate, Rule};
+
+fn main() -> Result<()> {
+    let (flags, args): (Vec<_>, Vec<_>) = env::args().skip(1).partition(|arg| arg == "--strict");
+    let strict = !flags.is_empty();
+    let input = InputSource::resolve(args.first().map(String::as_str), env!("CARGO_MANIFEST_DIR"));
+
+    // stdin can only be read once, files are streamed again for each part
+    let stdin = match input {
+        InputSource::Stdin => Some(input.read()?),
+        InputSource::File(_) => None,
+    };
+
+    for (part, rule) in [(1, Rule::Digits), (2, Rule::Spelled)] {
+        let reader: Box<dyn Read> = match &stdin {
+            Some(data) => Box::new(data.as_bytes()),
+            None => input.open()?,
+        };
+        let calibration = calibrate(reader, rule, strict)?;
+
+        for diagnostic in &calibration.diagnostics {
+            eprintln!("part {}: {}", part, diagnostic);
+        }
+        print_answer(part, calibration.total);
+    }
+
+    Ok(())
 }
@@ -0,0 +1,167 @@
+use crate::{vocabulary::fold, Vocabulary};
+
+/// Which characters count as digits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rule {
+    /// Only `0`-`9`, the part 1 rule.
+    Digits,
+    /// Digits and their English names, the part 2 rule.
+    #[default]
+    Spelled,
+}
+
+/// A digit ending at the byte just pushed, `len` bytes long.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Match {
+    pub digit: u8,
+    pub len: usize,
+}
+
+/// Finds digits, plain or spelled, one byte at a time.
+///
+/// Bytes are decoded into characters as they come, so words can use any
+/// letters. Every character is checked for a word ending on it, so
+/// overlapping words such as `twone` or `eightwo` both count. Only the last
+/// few characters, as many as the longest word has, are kept.
+#[derive(Debug)]
+pub struct Scanner {
+    vocabulary: Vocabulary,
+    /// Recent characters, and how many bytes each was read from.
+    window: Vec<char>,
+    widths: Vec<usize>,
+    filled: usize,
+    /// The start of a character still missing some bytes.
+    pending: Vec<u8>,
+}
+
+impl Scanner {
+    pub fn new(vocabulary: impl Into<Vocabulary>) -> Self {
+        let vocabulary = vocabulary.into();
+
+        let size = vocabulary.max_len().max(1);
+
+        Scanner {
+            window: vec!['\0'; size],
+            widths: vec![0; size],
+            vocabulary,
+            filled: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, byte: u8) -> Option<Match> {
+        if byte.is_ascii_digit() {
+            self.see(byte as char, 1);
+            return Some(Match {
+                digit: byte - b'0',
+                len: 1,
+            });
+        }
+
+        let (c, bytes) = self.decode(byte)?;
+        let c = match self.vocabulary.is_case_insensitive() {
+            true => fold(c),
+            false => c,
+        };
+        self.see(c, bytes);
+
+        let size = self.window.len();
+        let (digit, chars) = self
+            .vocabulary
+            .suffix_of(&self.window[size - self.filled..])?;
+
+        Some(Match {
+            digit,
+            len: self.widths[size - chars..].iter().sum(),
+        })
+    }
+
+    /// Forgets everything seen so far, words never span lines.
+    pub fn reset(&mut self) {
+        self.filled = 0;
+        self.pending.clear();
+    }
+
+    /// The character `byte` completes, if any, and how many bytes it took.
+    /// Bytes that are not valid UTF-8 read as `U+FFFD`, which no word
+    /// contains.
+    fn decode(&mut self, byte: u8) -> Option<(char, usize)> {
+        if self.pending.is_empty() && byte.is_ascii() {
+            return Some((byte as char, 1));
+        }
+
+        self.pending.push(byte);
+        let expected = match self.pending[0] {
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            0xf0..=0xf7 => 4,
+            _ => 1,
+        };
+        if self.pending.len() < expected {
+            return None;
+        }
+
+        let c = std::str::from_utf8(&self.pending)
+            .ok()
+            .and_then(|s| s.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+        let bytes = self.pending.len();
+        self.pending.clear();
+        Some((c, bytes))
+    }
+
+    fn see(&mut self, c: char, bytes: usize) {
+        let size = self.window.len();
+        self.window.copy_within(1.., 0);
+        self.widths.copy_within(1.., 0);
+        self.window[size - 1] = c;
+        self.widths[size - 1] = bytes;
+        self.filled = (self.filled + 1).min(self.window.len());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn digits(line: &str, vocabulary: impl Into<Vocabulary>) -> Vec<u8> {
+        let mut scanner = Scanner::new(vocabulary);
+        line.bytes()
+            .filter_map(|byte| scanner.push(byte))
+            .map(|found| found.digit)
+            .collect()
+    }
+
+    #[test]
+    fn overlapping_words() {
+        assert_eq!(digits("twone", Rule::Spelled), [2, 1]);
+        assert_eq!(digits("eightwothree", Rule::Spelled), [8, 2, 3]);
+        assert_eq!(digits("7pqrstsixteen", Rule::Spelled), [7, 6]);
+    }
+
+    #[test]
+    fn digits_rule_ignores_words() {
+        let mut scanner = Scanner::new(Rule::Digits);
+        let found = "one2three"
+            .bytes()
+            .filter_map(|byte| scanner.push(byte))
+            .collect::<Vec<_>>();
+
+        assert_eq!(found, [Match { digit: 2, len: 1 }]);
+    }
+
+    #[test]
+    fn other_vocabularies() {
+        assert_eq!(digits("quatreneufx", Vocabulary::french()), [4, 9]);
+        assert_eq!(digits("FÜNFzweiacht", Vocabulary::german()), [2, 8]);
+        assert_eq!(
+            digits("ZweiAcht", Vocabulary::german().ignore_case()),
+            [2, 8]
+        );
+        assert_eq!(digits("FÜNFxx", Vocabulary::german().ignore_case()), [5]);
+        assert_eq!(
+            digits("eerhtowt", Vocabulary::english().with_reversed()),
+            [3, 2]
+        );
+    }
+}
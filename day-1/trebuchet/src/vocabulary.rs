@@ -0,0 +1,190 @@
+use std::{fs, path::Path};
+
+use aoc_common::{bail, AocError, Context, Result};
+
+use crate::Rule;
+
+/// Words the scanner reads as digits, on top of `0`-`9` themselves.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Vocabulary {
+    words: Vec<(Vec<char>, u8)>,
+    ignore_case: bool,
+}
+
+/// Lowercases `c` when that gives a single character, `Ä` to `ä` but
+/// leaves `İ` alone. Words and input are folded alike.
+pub(crate) fn fold(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(lower), None) => lower,
+        _ => c,
+    }
+}
+
+impl Vocabulary {
+    /// No words at all, only plain digits are read.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fails on digits above 9.
+    pub fn from_words<'a>(words: impl IntoIterator<Item = (&'a str, u8)>) -> Result<Self> {
+        let words = words.into_iter().collect::<Vec<_>>();
+
+        if let Some((word, digit)) = words.iter().find(|(_, digit)| *digit > 9) {
+            bail!("`{}` stands for {}, which is not a digit", word, digit);
+        }
+
+        Ok(Self::checked(words))
+    }
+
+    pub fn english() -> Self {
+        Self::numbered([
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ])
+    }
+
+    pub fn french() -> Self {
+        Self::numbered([
+            "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ])
+    }
+
+    pub fn german() -> Self {
+        Self::numbered([
+            "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ])
+    }
+
+    /// A built-in vocabulary by its English or ISO 639-1 name.
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "english" | "en" => Some(Self::english()),
+            "french" | "fr" => Some(Self::french()),
+            "german" | "de" => Some(Self::german()),
+            _ => None,
+        }
+    }
+
+    /// Reads a word list: one `word digit` pair per line, blank lines and
+    /// lines starting with `#` are skipped.
+    pub fn parse(list: &str) -> Result<Self, AocError> {
+        let mut words = Vec::new();
+
+        for (index, line) in list.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |message: &str| AocError::Parse {
+                line: index + 1,
+                message: format!("{}: `{}`", message, line),
+            };
+
+            let (word, digit) = line
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| error("expected `word digit`"))?;
+            let digit = match digit.trim().parse::<u8>() {
+                Ok(digit) if digit <= 9 => digit,
+                _ => return Err(error("not a digit")),
+            };
+
+            words.push((word, digit));
+        }
+
+        Ok(Self::checked(words))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let list = fs::read_to_string(path)
+            .with_context(|| format!("could not read word list `{}`", path.display()))?;
+
+        Ok(Self::parse(&list)?)
+    }
+
+    /// Matches words regardless of case, `FÜNF` as well as `fünf`.
+    pub fn ignore_case(mut self) -> Self {
+        self.words
+            .iter_mut()
+            .for_each(|(word, _)| word.iter_mut().for_each(|c| *c = fold(*c)));
+        self.ignore_case = true;
+        self
+    }
+
+    /// Also reads every word spelled backwards, `eno` as 1.
+    pub fn with_reversed(mut self) -> Self {
+        let reversed = self
+            .words
+            .iter()
+            .map(|(word, digit)| (word.iter().rev().copied().collect(), *digit))
+            .collect::<Vec<_>>();
+
+        self.words.extend(reversed);
+        self
+    }
+
+    pub fn is_case_insensitive(&self) -> bool {
+        self.ignore_case
+    }
+
+    /// Length in characters of the longest word.
+    pub fn max_len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|(word, _)| word.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The word `recent` ends with, as its digit and length in characters.
+    pub(crate) fn suffix_of(&self, recent: &[char]) -> Option<(u8, usize)> {
+        self.words
+            .iter()
+            .find(|(word, _)| recent.ends_with(word))
+            .map(|(word, digit)| (*digit, word.len()))
+    }
+
+    fn numbered(words: [&str; 9]) -> Self {
+        Self::checked(words.into_iter().zip(1..))
+    }
+
+    /// Builds from words whose digits are known to be at most 9.
+    fn checked<'a>(words: impl IntoIterator<Item = (&'a str, u8)>) -> Self {
+        Vocabulary {
+            words: words
+                .into_iter()
+                .map(|(word, digit)| (word.chars().collect(), digit))
+                .collect(),
+            ignore_case: false,
+        }
+    }
+}
+
+impl From<Rule> for Vocabulary {
+    fn from(rule: Rule) -> Self {
+        match rule {
+            Rule::Digits => Vocabulary::new(),
+            Rule::Spelled => Vocabulary::english(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_word_list() {
+        let vocabulary = Vocabulary::parse("# Dutch\neen 1\n\ntwee  2\n").unwrap();
+        assert_eq!(
+            vocabulary,
+            Vocabulary::from_words([("een", 1), ("twee", 2)]).unwrap()
+        );
+
+        assert!(Vocabulary::parse("tien 10").is_err());
+        assert!(Vocabulary::parse("tien").is_err());
+        assert!(Vocabulary::from_words([("dertig", 30)]).is_err());
+    }
+}
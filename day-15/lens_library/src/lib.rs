@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+
+use aoc_common::{Answer, AocError, Result, Solver};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::{is_alphanumeric, is_digit},
+    combinator::{all_consuming, map, map_res, opt},
+    Finish, IResult,
+};
+
+#[derive(Clone)]
+struct Instruction {
+    length: Option<usize>,
+    label: String,
+    operation: char,
+}
+
+fn hash(value: &str) -> usize {
+    value
+        .chars()
+        .fold(0, |acc, x| ((acc + x as usize) * 17) % 256)
+}
+
+impl Instruction {
+    fn hash_label(&self) -> usize {
+        hash(&self.label)
+    }
+}
+
+impl PartialEq for Instruction {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+    }
+}
+impl std::fmt::Debug for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Instruction: {} {} {:?}",
+            self.label, self.operation, self.length
+        )
+    }
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    let (input, label) = take_while1(|c| is_alphanumeric(c as u8))(input)?;
+    let (input, operation): (&str, char) = map(alt((tag("="), tag("-"))), |s: &str| {
+        s.chars().last().unwrap()
+    })(input)?;
+
+    let (input, length) = opt(map_res(take_while1(|u| is_digit(u as u8)), |a: &str| {
+        a.parse::<usize>()
+    }))(input)?;
+
+    Ok((
+        input,
+        Instruction {
+            label: label.to_string(),
+            operation,
+            length,
+        },
+    ))
+}
+
+/// Parses every comma separated step of `data`. A `=` step needs a focal
+/// length, a `-` step must not have one.
+fn parse_steps(data: &str) -> Result<Vec<Instruction>> {
+    let mut steps = Vec::new();
+
+    for (index, line) in data.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        for step in line.split(',') {
+            let instruction = all_consuming(parse_instruction)(step)
+                .finish()
+                .ok()
+                .map(|(_, instruction)| instruction)
+                .filter(|ins| (ins.operation == '=') == ins.length.is_some())
+                .ok_or_else(|| AocError::Parse {
+                    line: index + 1,
+                    message: format!("`{}` is not a step", step),
+                })?;
+
+            steps.push(instruction);
+        }
+    }
+
+    Ok(steps)
+}
+
+pub fn part1(data: &str) -> Result<Answer> {
+    let sum: usize = data
+        .lines()
+        .map(|line| line.split(',').map(hash).sum::<usize>())
+        .sum();
+
+    Ok(sum.into())
+}
+
+pub fn part2(data: &str) -> Result<Answer> {
+    let mut entries: BTreeMap<usize, Vec<Instruction>> = BTreeMap::new();
+
+    for instruction in parse_steps(data)? {
+        entries
+            .entry(instruction.hash_label())
+            .and_modify(|vecs| {
+                if let Some(index) = vecs.iter().position(|x| *x == instruction) {
+                    if instruction.operation == '=' {
+                        vecs[index] = instruction.clone();
+                    } else {
+                        vecs.remove(index);
+                    }
+                } else {
+                    vecs.push(instruction.clone());
+                }
+            })
+            .or_insert(vec![instruction.clone()]);
+    }
+
+    let sum: usize = entries
+        .iter()
+        .map(|(key, instructions)| {
+            instructions
+                .iter()
+                .enumerate()
+                .map(|(index, ins)| (key + 1) * (index + 1) * ins.length.unwrap_or(0))
+                .sum::<usize>()
+        })
+        .sum();
+
+    Ok(sum.into())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn crate_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<usize>> {
+        Some(parse_steps(input).map(|instructions| instructions.len()))
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        part2(input)
+    }
+}
+
+#[test]
+fn test_parse_box() {
+    assert_eq!(
+        parse_instruction("cp=2"),
+        Ok((
+            "",
+            Instruction {
+                label: "cp".to_string(),
+                operation: '=',
+                length: Some(2),
+            }
+        ))
+    );
+
+    assert_eq!(
+        parse_instruction("cp-"),
+        Ok((
+            "",
+            Instruction {
+                label: "cp".to_string(),
+                operation: '-',
+                length: None,
+            }
+        ))
+    )
+}
+
+#[test]
+fn test_bad_steps() {
+    assert_eq!(
+        part2("rn=1,cm").unwrap_err().to_string(),
+        "parse error on line 1: `cm` is not a step"
+    );
+    assert!(part2("rn=").is_err());
+}
@@ -0,0 +1,41 @@
+//! Answers the bag inference queries for a list of games.
+//!
+//! Usage: `queries [input] [budget]`, the budget defaults to the size of the
+//! part 1 bag.
+
+use aoc_common::{load_input, Result};
+use cube_coundrum::{inference, parse_games, puzzle_bag};
+
+fn main() -> Result<()> {
+    let data = load_input!()?;
+    let games = parse_games(&data)?;
+    let bag = puzzle_bag();
+
+    let budget = match std::env::args().nth(2) {
+        Some(budget) => budget.parse()?,
+        None => bag.iter().map(|(_, count)| count).sum(),
+    };
+
+    println!("Minimal bag: {}", inference::minimal_bag(&games));
+
+    let plan = inference::best_bag_for_budget(&games, budget);
+    println!(
+        "Best bag for {} cubes: {} ({} games)",
+        budget,
+        plan.bag,
+        plan.games.len()
+    );
+
+    match inference::best_limit_change(&games, &bag) {
+        Some(change) => println!(
+            "Best single change: {} from {} to {} admits {} more games",
+            change.color,
+            change.from,
+            change.to,
+            change.admitted.len()
+        ),
+        None => println!("No single limit change admits another game"),
+    }
+
+    Ok(())
+}
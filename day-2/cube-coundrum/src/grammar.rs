@@ -0,0 +1,253 @@
+//! The game line grammar:
+//!
+//! ```text
+//! game  = "Game" id ":" draw (";" draw)*
+//! draw  = pick ("," pick)*
+//! pick  = amount color
+//! color = any color of the palette, "red" | "green" | "blue" by default
+//! ```
+//!
+//! with any amount of spaces between tokens.
+
+use std::str::FromStr;
+
+use miette::{Diagnostic, SourceSpan};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, char, digit1, space0, space1},
+    combinator::{all_consuming, cut, eof, map_res},
+    error::{ErrorKind, FromExternalError, ParseError},
+    multi::separated_list1,
+    Finish, IResult,
+};
+use thiserror::Error;
+
+use crate::{Cube, Palette, Set};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GameErrorKind {
+    #[error("expected {0}")]
+    Expected(&'static str),
+
+    #[error("unknown color `{0}`")]
+    UnknownColor(String),
+
+    #[error("`{0}` appears twice in one draw")]
+    DuplicateColor(String),
+}
+
+/// A game line that does not follow the grammar, pointing at the offending
+/// part of the line.
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("line {line}: {kind}")]
+#[diagnostic(code(cube_coundrum::parse))]
+pub struct GameError {
+    /// 1-based line number.
+    pub line: usize,
+    pub kind: GameErrorKind,
+    #[source_code]
+    pub src: String,
+    #[label("{kind}")]
+    pub span: SourceSpan,
+}
+
+/// Where parsing stopped. `kind` is `None` for plain nom errors until
+/// [`expect`] says what should have been there.
+#[derive(Debug)]
+struct Failure<'a> {
+    at: &'a str,
+    len: usize,
+    kind: Option<GameErrorKind>,
+}
+
+impl<'a> Failure<'a> {
+    fn new(at: &'a str, len: usize, kind: GameErrorKind) -> nom::Err<Self> {
+        nom::Err::Failure(Failure {
+            at,
+            len,
+            kind: Some(kind),
+        })
+    }
+}
+
+impl<'a> ParseError<&'a str> for Failure<'a> {
+    fn from_error_kind(input: &'a str, _kind: ErrorKind) -> Self {
+        let token = input.find([' ', ',', ';', ':']).unwrap_or(input.len());
+
+        Failure {
+            at: input,
+            len: token.max(input.chars().next().map_or(0, char::len_utf8)),
+            kind: None,
+        }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Failure<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _error: E) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+}
+
+type PResult<'a, O> = IResult<&'a str, O, Failure<'a>>;
+
+/// Names what `parser` was looking for when it fails without saying.
+fn expect<'a, O>(
+    what: &'static str,
+    mut parser: impl FnMut(&'a str) -> PResult<'a, O>,
+) -> impl FnMut(&'a str) -> PResult<'a, O> {
+    move |input| {
+        parser(input).map_err(|err| {
+            err.map(|failure| Failure {
+                kind: failure.kind.or(Some(GameErrorKind::Expected(what))),
+                ..failure
+            })
+        })
+    }
+}
+
+fn number<T: FromStr>(input: &str) -> PResult<'_, T> {
+    expect("a number", map_res(digit1, str::parse))(input)
+}
+
+fn pick<'a>(palette: &'a Palette) -> impl FnMut(&'a str) -> PResult<'a, (&'a str, u32)> {
+    move |input| {
+        let (input, _) = space0(input)?;
+        let (input, count) = number(input)?;
+        let (input, _) = expect("a space", space1)(input)?;
+        let (rest, color) = expect("a color", alpha1)(input)?;
+
+        if !palette.contains(color) {
+            return Err(Failure::new(
+                color,
+                color.len(),
+                GameErrorKind::UnknownColor(color.to_string()),
+            ));
+        }
+
+        let (rest, _) = space0(rest)?;
+        Ok((rest, (color, count)))
+    }
+}
+
+fn draw<'a>(palette: &'a Palette) -> impl FnMut(&'a str) -> PResult<'a, Set> {
+    move |input| {
+        let (rest, picks) = separated_list1(char(','), cut(pick(palette)))(input)?;
+        let mut set = Set::default();
+
+        for (color, count) in picks {
+            if set.insert(color, count).is_some() {
+                return Err(Failure::new(
+                    color,
+                    color.len(),
+                    GameErrorKind::DuplicateColor(color.to_string()),
+                ));
+            }
+        }
+
+        Ok((rest, set))
+    }
+}
+
+fn game<'a>(palette: &'a Palette) -> impl FnMut(&'a str) -> PResult<'a, Cube> {
+    move |input| {
+        let (input, _) = expect("`Game`", tag("Game"))(input)?;
+        let (input, _) = expect("a space", space1)(input)?;
+        let (input, id) = number(input)?;
+        let (input, _) = space0(input)?;
+        let (input, _) = expect("`:`", char(':'))(input)?;
+        let (input, sets) = separated_list1(char(';'), draw(palette))(input)?;
+        let (input, _) = expect("`,`, `;` or the end of the line", eof)(input)?;
+
+        Ok((input, Cube { id, sets }))
+    }
+}
+
+fn run<'a, O>(
+    mut parser: impl FnMut(&'a str) -> PResult<'a, O>,
+    src: &'a str,
+    line: usize,
+) -> Result<O, GameError> {
+    parser(src)
+        .finish()
+        .map(|(_, value)| value)
+        .map_err(|failure| GameError {
+            line,
+            kind: failure
+                .kind
+                .unwrap_or(GameErrorKind::Expected("the end of the input")),
+            src: src.to_string(),
+            span: (src.len() - failure.at.len(), failure.len).into(),
+        })
+}
+
+pub(crate) fn parse_draw(src: &str, palette: &Palette) -> Result<Set, GameError> {
+    run(all_consuming(draw(palette)), src.trim_end(), 1)
+}
+
+/// Parses a single game line, `line` is only used for error reporting.
+pub fn parse_game(src: &str, line: usize) -> Result<Cube, GameError> {
+    parse_game_with(src, line, &Palette::default())
+}
+
+/// Like [`parse_game`], for games played with other colors.
+pub fn parse_game_with(src: &str, line: usize, palette: &Palette) -> Result<Cube, GameError> {
+    run(game(palette), src.trim_end(), line)
+}
+
+/// Parses every non-blank line of `input` as a game.
+pub fn parse_games(input: &str) -> Result<Vec<Cube>, GameError> {
+    parse_games_with(input, &Palette::default())
+}
+
+/// Like [`parse_games`], for games played with other colors.
+pub fn parse_games_with(input: &str, palette: &Palette) -> Result<Vec<Cube>, GameError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse_game_with(line, index + 1, palette))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn error(line: &str) -> (GameErrorKind, &str) {
+        let error = parse_game(line, 1).unwrap_err();
+        let span = error.span.offset()..error.span.offset() + error.span.len();
+
+        (error.kind, &line[span])
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        assert_eq!(
+            error("Game x: 3 purple"),
+            (GameErrorKind::Expected("a number"), "x")
+        );
+        assert_eq!(
+            error("Game 1: 3 purple"),
+            (GameErrorKind::UnknownColor("purple".to_string()), "purple")
+        );
+        assert_eq!(
+            error("Game 1: 1 red; 3 green, 2 red, 4 green"),
+            (GameErrorKind::DuplicateColor("green".to_string()), "green")
+        );
+        assert_eq!(
+            error("Game 1: 3 red 4 blue"),
+            (
+                GameErrorKind::Expected("`,`, `;` or the end of the line"),
+                "4"
+            )
+        );
+        assert_eq!(
+            error("Game 1: 3 red;"),
+            (GameErrorKind::Expected("a number"), "")
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Questions about which bags could have produced a list of games.
+
+use std::collections::BTreeSet;
+
+use crate::{Bag, Cube};
+
+/// The smallest bag every one of `games` could have been played with.
+pub fn minimal_bag(games: &[Cube]) -> Bag {
+    let mut bag = Bag::default();
+    games
+        .iter()
+        .for_each(|game| bag.max_with(&game.minimal_bag()));
+    bag
+}
+
+/// A bag picked for a cube budget and the games it makes possible.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetPlan {
+    pub bag: Bag,
+    pub games: Vec<u32>,
+}
+
+impl BudgetPlan {
+    pub fn total(&self) -> u32 {
+        self.bag.iter().map(|(_, count)| count).sum()
+    }
+}
+
+/// The bag of at most `budget` cubes that makes the most games possible,
+/// the one with the fewest cubes among equally good bags.
+///
+/// Only counts some game needs are worth trying for each color, so the
+/// search is exhaustive over those; it grows with the number of colors.
+pub fn best_bag_for_budget(games: &[Cube], budget: u32) -> BudgetPlan {
+    let needs = games.iter().map(Cube::minimal_bag).collect::<Vec<_>>();
+    let colors = needs
+        .iter()
+        .flat_map(|bag| bag.iter().map(|(color, _)| color.to_string()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let mut search = Search {
+        needs: &needs,
+        colors: &colors,
+        best: (Vec::new(), Bag::default(), 0),
+    };
+    search.run(0, (0..needs.len()).collect(), Bag::default(), budget);
+
+    let (fitting, bag, _) = search.best;
+    BudgetPlan {
+        bag,
+        games: fitting.into_iter().map(|index| games[index].id).collect(),
+    }
+}
+
+struct Search<'a> {
+    needs: &'a [Bag],
+    colors: &'a [String],
+    /// Indices of the games that fit, the bag and its total.
+    best: (Vec<usize>, Bag, u32),
+}
+
+impl Search<'_> {
+    fn run(&mut self, color: usize, fitting: Vec<usize>, bag: Bag, left: u32) {
+        if fitting.len() < self.best.0.len() {
+            return;
+        }
+
+        let Some(name) = self.colors.get(color) else {
+            let total = bag.iter().map(|(_, count)| count).sum();
+            let (best, _, best_total) = &self.best;
+
+            if fitting.len() > best.len() || (fitting.len() == best.len() && total < *best_total) {
+                self.best = (fitting, bag, total);
+            }
+            return;
+        };
+
+        let counts = fitting
+            .iter()
+            .map(|&index| self.needs[index].get(name))
+            .filter(|&count| count <= left)
+            .chain([0])
+            .collect::<BTreeSet<_>>();
+
+        for count in counts.into_iter().rev() {
+            let fits = fitting
+                .iter()
+                .copied()
+                .filter(|&index| self.needs[index].get(name) <= count)
+                .collect();
+
+            let mut bag = bag.clone();
+            if count > 0 {
+                bag.insert(name.as_str(), count);
+            }
+            self.run(color + 1, fits, bag, left - count);
+        }
+    }
+}
+
+/// Raising a single color's limit, and the games that would admit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitChange {
+    pub color: String,
+    pub from: u32,
+    pub to: u32,
+    pub admitted: Vec<u32>,
+}
+
+/// The one color whose limit, raised on its own, makes the most games that
+/// are impossible with `bag` possible. Ties go to the smaller raise. `None`
+/// when no single change admits anything.
+pub fn best_limit_change(games: &[Cube], bag: &Bag) -> Option<LimitChange> {
+    let needs = games.iter().map(Cube::minimal_bag).collect::<Vec<_>>();
+    let colors = needs
+        .iter()
+        .flat_map(|need| need.iter().map(|(color, _)| color.to_string()))
+        .collect::<BTreeSet<_>>();
+
+    let mut best: Option<LimitChange> = None;
+
+    for color in colors {
+        let from = bag.get(&color);
+        let mut to = from;
+        let mut admitted = Vec::new();
+
+        for (game, need) in games.iter().zip(&needs) {
+            let blocked_only_here = need.get(&color) > from
+                && need
+                    .iter()
+                    .all(|(other, count)| other == color || count <= bag.get(other));
+
+            if blocked_only_here {
+                to = to.max(need.get(&color));
+                admitted.push(game.id);
+            }
+        }
+
+        let better = match &best {
+            _ if admitted.is_empty() => false,
+            None => true,
+            Some(best) => {
+                admitted.len() > best.admitted.len()
+                    || (admitted.len() == best.admitted.len() && to - from < best.to - best.from)
+            }
+        };
+
+        if better {
+            best = Some(LimitChange {
+                color,
+                from,
+                to,
+                admitted,
+            });
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse_games, puzzle_bag};
+
+    const EXAMPLE: &str = include_str!("../data/example.txt");
+
+    #[test]
+    fn queries_on_the_example() {
+        let games = parse_games(EXAMPLE).unwrap();
+
+        assert_eq!(
+            minimal_bag(&games),
+            Bag::from_iter([("red", 20), ("green", 13), ("blue", 15)])
+        );
+
+        let plan = best_bag_for_budget(&games, 39);
+        assert_eq!(plan.games, [1, 2, 4, 5]);
+        assert_eq!(plan.total(), 32);
+
+        assert_eq!(
+            best_limit_change(&games, &puzzle_bag()),
+            Some(LimitChange {
+                color: "red".to_string(),
+                from: 12,
+                to: 20,
+                admitted: vec![3],
+            })
+        );
+    }
+}
@@ -0,0 +1,321 @@
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use anyhow::Result;
+use aoc_common::{Answer, Solver};
+
+mod grammar;
+pub mod inference;
+
+pub use grammar::{
+    parse_game, parse_game_with, parse_games, parse_games_with, GameError, GameErrorKind,
+};
+
+/// The colors a game may use. Defaults to the puzzle's red, green and blue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<String>,
+}
+
+impl Palette {
+    pub fn new<S: Into<String>>(colors: impl IntoIterator<Item = S>) -> Self {
+        Palette {
+            colors: colors.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn contains(&self, color: &str) -> bool {
+        self.colors.iter().any(|known| known == color)
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.colors.iter().map(String::as_str)
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::new(["red", "green", "blue"])
+    }
+}
+
+/// Cubes by color: one handful shown during a game, or the content of a
+/// bag. Colors that are not listed count as zero.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Set {
+    counts: BTreeMap<String, u32>,
+}
+
+/// How many cubes of each color a bag holds.
+pub type Bag = Set;
+
+impl Set {
+    pub fn get(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    /// Sets the count of `color`, returning the previous one.
+    pub fn insert(&mut self, color: impl Into<String>, count: u32) -> Option<u32> {
+        self.counts.insert(color.into(), count)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(color, &count)| (color.as_str(), count))
+    }
+
+    /// Raises every count to at least the one in `other`.
+    pub fn max_with(&mut self, other: &Set) {
+        for (color, count) in other.iter() {
+            let current = self.counts.entry(color.to_string()).or_default();
+            *current = (*current).max(count);
+        }
+    }
+
+    /// Product of the counts of every color in `palette`.
+    pub fn power(&self, palette: &Palette) -> u64 {
+        palette
+            .colors()
+            .map(|color| u64::from(self.get(color)))
+            .product()
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u32)> for Set {
+    fn from_iter<T: IntoIterator<Item = (S, u32)>>(iter: T) -> Self {
+        Set {
+            counts: iter
+                .into_iter()
+                .map(|(color, count)| (color.into(), count))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Set {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (color, count)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", count, color)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Set {
+    type Err = GameError;
+
+    fn from_str(s: &str) -> Result<Self, GameError> {
+        grammar::parse_draw(s, &Palette::default())
+    }
+}
+
+/// A game: its id and every draw made from the bag.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cube {
+    pub id: u32,
+    pub sets: Vec<Set>,
+}
+
+impl FromStr for Cube {
+    type Err = GameError;
+
+    fn from_str(s: &str) -> Result<Self, GameError> {
+        grammar::parse_game(s, 1)
+    }
+}
+
+/// The bag part 1 asks about.
+pub fn puzzle_bag() -> Bag {
+    Bag::from_iter([("red", 12), ("green", 13), ("blue", 14)])
+}
+
+/// Why a game could not have been played with a bag: the first draw that
+/// shows more cubes of a color than the bag holds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Impossible {
+    pub game: u32,
+    /// 0-based index of the draw within the game.
+    pub draw: usize,
+    pub color: String,
+    pub count: u32,
+    pub limit: u32,
+}
+
+impl fmt::Display for Impossible {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "game {} draw {} shows {} {} but the bag only holds {}",
+            self.game,
+            self.draw + 1,
+            self.count,
+            self.color,
+            self.limit
+        )
+    }
+}
+
+impl Cube {
+    /// Checks whether this game could have been played with `bag`.
+    pub fn check(&self, bag: &Bag) -> Result<(), Impossible> {
+        for (draw, set) in self.sets.iter().enumerate() {
+            for (color, count) in set.iter() {
+                let limit = bag.get(color);
+
+                if count > limit {
+                    return Err(Impossible {
+                        game: self.id,
+                        draw,
+                        color: color.to_string(),
+                        count,
+                        limit,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The fewest cubes of each color that make this game possible.
+    pub fn minimal_bag(&self) -> Bag {
+        let mut bag = Bag::default();
+        self.sets.iter().for_each(|set| bag.max_with(set));
+        bag
+    }
+}
+
+/// Splits `games` into the ids of those possible with `bag` and the
+/// reasons the others are not.
+pub fn possible_games(games: &[Cube], bag: &Bag) -> (Vec<u32>, Vec<Impossible>) {
+    let mut possible = Vec::new();
+    let mut impossible = Vec::new();
+
+    for game in games {
+        match game.check(bag) {
+            Ok(()) => possible.push(game.id),
+            Err(reason) => impossible.push(reason),
+        }
+    }
+
+    (possible, impossible)
+}
+
+pub fn part1(data: &str) -> Result<Answer> {
+    let (possible, _) = possible_games(&parse_games(data)?, &puzzle_bag());
+
+    Ok(possible.iter().sum::<u32>().into())
+}
+
+pub fn part2(data: &str) -> Result<Answer> {
+    let palette = Palette::default();
+    let total: u64 = parse_games(data)?
+        .iter()
+        .map(|game| game.minimal_bag().power(&palette))
+        .sum();
+
+    Ok(total.into())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn crate_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<usize>> {
+        Some(
+            parse_games(input)
+                .map(|cubes| cubes.len())
+                .map_err(Into::into),
+        )
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    #[test]
+    fn impossible_games_name_the_draw() {
+        let game = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green"
+            .parse::<Cube>()
+            .unwrap();
+
+        assert_eq!(
+            game.check(&puzzle_bag()),
+            Err(Impossible {
+                game: 3,
+                draw: 0,
+                color: "red".to_string(),
+                count: 20,
+                limit: 12,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_color() {
+        assert_eq!("3 green".parse::<Set>().unwrap().get("green"), 3);
+        assert_eq!("2 blue".parse::<Set>().unwrap().get("blue"), 2);
+        assert_eq!("2 red".parse::<Set>().unwrap().get("red"), 2);
+    }
+
+    #[test]
+    fn parse_sets() {
+        assert_eq!(
+            "4 blue, 3 green, 2 red".parse::<Set>().unwrap(),
+            Set::from_iter([("green", 3), ("red", 2), ("blue", 4)])
+        );
+        assert_eq!(
+            "4 blue".parse::<Set>().unwrap(),
+            Set::from_iter([("blue", 4)])
+        );
+    }
+
+    #[test]
+    fn draw() {
+        assert_eq!(
+            "Game 1: 3 blue,  2 red; 3 green, 2 blue"
+                .parse::<Cube>()
+                .unwrap(),
+            Cube {
+                id: 1,
+                sets: vec![
+                    Set::from_iter([("blue", 3), ("red", 2)]),
+                    Set::from_iter([("blue", 2), ("green", 3)]),
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn other_palettes() {
+        let palette = Palette::new(["cyan", "magenta", "yellow", "black"]);
+        let game = parse_game_with(
+            "Game 7: 2 cyan, 1 black; 3 magenta, 4 yellow, 2 black",
+            1,
+            &palette,
+        )
+        .unwrap();
+
+        assert_eq!(game.minimal_bag().power(&palette), 2 * 3 * 4 * 2);
+        assert!(parse_game_with("Game 7: 2 red", 1, &palette).is_err());
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Grid, Pos, Result, Solver};
+
+mod symbols;
+
+pub use symbols::SymbolPolicy;
+
+#[derive(Debug)]
+struct Number {
+    content: String,
+    row: usize,
+    start: usize,
+    end: usize,
+}
+
+/// The runs of digits of every row, in grid columns rather than bytes so
+/// that wide characters elsewhere on the row do not shift them.
+fn numbers(grid: &Grid<char>) -> impl Iterator<Item = Number> + '_ {
+    grid.rows().enumerate().flat_map(|(row, cells)| {
+        let mut numbers = Vec::new();
+        let mut col = 0;
+
+        while col < cells.len() {
+            let len = cells[col..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+
+            if len > 0 {
+                numbers.push(Number {
+                    content: cells[col..col + len].iter().collect(),
+                    row,
+                    start: col,
+                    end: col + len,
+                });
+            }
+            col += len.max(1);
+        }
+
+        numbers
+    })
+}
+
+pub fn part1(data: &str) -> Result<Answer> {
+    part1_with(data, &SymbolPolicy::default())
+}
+
+/// Sums the part numbers, with symbols picked by `policy`.
+pub fn part1_with(data: &str, policy: &SymbolPolicy) -> Result<Answer> {
+    let grid: Grid<char> = data.parse()?;
+    let symbols = policy.symbols(&grid);
+
+    let sum: i32 = numbers(&grid)
+        .filter(|number| {
+            grid.span_neighbours(number.row, number.start..number.end)
+                .any(|pos| symbols.contains(&grid[pos]))
+        })
+        .map(|number| number.content.parse::<i32>())
+        .sum::<Result<_, _>>()?;
+
+    Ok(sum.into())
+}
+
+/// Sums the gear ratios: the product of the two numbers around every `*`
+/// that touches exactly two.
+pub fn part2(data: &str) -> Result<Answer> {
+    let grid: Grid<char> = data.parse()?;
+    let mut gears: HashMap<Pos, Vec<i64>> = HashMap::new();
+
+    for number in numbers(&grid) {
+        let value = number.content.parse::<i64>()?;
+
+        grid.span_neighbours(number.row, number.start..number.end)
+            .filter(|&pos| grid[pos] == '*')
+            .for_each(|pos| gears.entry(pos).or_default().push(value));
+    }
+
+    let sum: i64 = gears
+        .values()
+        .filter(|numbers| numbers.len() == 2)
+        .map(|numbers| numbers[0] * numbers[1])
+        .sum();
+
+    Ok(sum.into())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn crate_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example.txt");
+
+    #[test]
+    fn policies_agree_on_the_example() {
+        let only = SymbolPolicy::only("*#+$".chars());
+
+        for policy in [SymbolPolicy::NotDigitOrDot, SymbolPolicy::FromInput, only] {
+            assert_eq!(part1_with(EXAMPLE, &policy).unwrap(), Answer::from(4361));
+        }
+    }
+
+    #[test]
+    fn wide_symbols_do_not_shift_numbers() {
+        assert_eq!(part1("€..5\n....\n").unwrap(), Answer::from(0));
+        assert_eq!(part1("€5..\n....\n").unwrap(), Answer::from(5));
+        assert_eq!(part2("7€8.\n....\n").unwrap(), Answer::from(0));
+        assert_eq!(part2("7*8€\n....\n").unwrap(), Answer::from(56));
+    }
+
+    #[test]
+    fn other_filler_characters() {
+        let schematic = EXAMPLE.replace('.', " ");
+
+        assert_eq!(
+            part1_with(&schematic, &SymbolPolicy::FromInput).unwrap(),
+            Answer::from(4361)
+        );
+        assert_ne!(
+            part1_with(&schematic, &SymbolPolicy::NotDigitOrDot).unwrap(),
+            Answer::from(4361)
+        );
+    }
+}
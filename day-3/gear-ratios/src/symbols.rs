@@ -0,0 +1,76 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use aoc_common::Grid;
+
+/// Decides which characters of a schematic count as symbols.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum SymbolPolicy {
+    /// Anything that is neither a digit nor `.`, the puzzle's own rule.
+    #[default]
+    NotDigitOrDot,
+    /// Treat the most common non-digit character as the background and
+    /// everything else that is not a digit as a symbol, for inputs that use
+    /// something other than `.` as filler. Ties go to `.`, then to the
+    /// lowest character.
+    FromInput,
+    /// Exactly these characters.
+    Only(HashSet<char>),
+}
+
+impl SymbolPolicy {
+    pub fn only(symbols: impl IntoIterator<Item = char>) -> Self {
+        SymbolPolicy::Only(symbols.into_iter().collect())
+    }
+
+    /// The symbols of `grid` under this policy.
+    pub fn symbols(&self, grid: &Grid<char>) -> HashSet<char> {
+        let candidates = grid.iter().map(|(_, &c)| c).filter(|c| !c.is_ascii_digit());
+
+        match self {
+            SymbolPolicy::NotDigitOrDot => candidates.filter(|&c| c != '.').collect(),
+            SymbolPolicy::FromInput => {
+                let mut counts: HashMap<char, usize> = HashMap::new();
+                candidates.for_each(|c| *counts.entry(c).or_default() += 1);
+
+                let background = counts
+                    .iter()
+                    .max_by_key(|(&c, &count)| (count, c == '.', Reverse(c)))
+                    .map(|(&c, _)| c);
+
+                counts
+                    .into_keys()
+                    .filter(|&c| Some(c) != background)
+                    .collect()
+            }
+            SymbolPolicy::Only(symbols) => symbols.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn background_is_derived_from_input() {
+        let grid: Grid<char> = "12_~\n__3_\n".parse().unwrap();
+
+        assert_eq!(SymbolPolicy::FromInput.symbols(&grid), HashSet::from(['~']));
+        assert_eq!(
+            SymbolPolicy::NotDigitOrDot.symbols(&grid),
+            HashSet::from(['_', '~'])
+        );
+    }
+
+    #[test]
+    fn ties_are_broken_the_same_way_every_time() {
+        let symbols =
+            |schematic: &str| SymbolPolicy::FromInput.symbols(&schematic.parse().unwrap());
+
+        assert_eq!(symbols("#.1#.\n"), HashSet::from(['#']));
+        assert_eq!(symbols("~_1~_\n"), HashSet::from(['~']));
+    }
+}
@@ -0,0 +1,37 @@
+//! Shows which cards won the copies of every other card.
+//!
+//! Usage: `cascade [input] [--dot]`. With `--dot` the cascade is printed as
+//! a Graphviz graph, e.g. `cascade - --dot < data.txt | dot -Tsvg`.
+
+use std::env;
+
+use aoc_common::{InputSource, Result};
+use scrachcards::{cascade, parse_cards};
+
+fn main() -> Result<()> {
+    let (flags, args): (Vec<_>, Vec<_>) = env::args().skip(1).partition(|arg| arg == "--dot");
+    let input = InputSource::resolve(args.first().map(String::as_str), env!("CARGO_MANIFEST_DIR"));
+    let cascade = cascade::cascade(&parse_cards(&input.read()?)?)?;
+
+    if !flags.is_empty() {
+        print!("{}", cascade::to_dot(&cascade));
+        return Ok(());
+    }
+
+    for card in &cascade {
+        let parents = card
+            .parents
+            .iter()
+            .map(|(parent, copies)| format!("{} from card {}", copies, parent))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        if parents.is_empty() {
+            println!("Card {}: {} copies", card.id, card.copies);
+        } else {
+            println!("Card {}: {} copies ({})", card.id, card.copies, parents);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::{collections::BTreeSet, str::FromStr};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::AocError;
+
+/// One scratchcard: the winning numbers on the left of the `|` and the
+/// numbers held on the right.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scratchcard {
+    pub id: u32,
+    pub winning: BTreeSet<u32>,
+    pub held: BTreeSet<u32>,
+}
+
+impl Scratchcard {
+    /// How many held numbers are winning ones. A number listed twice on the
+    /// same side still counts once.
+    pub fn matches(&self) -> usize {
+        self.winning.intersection(&self.held).count()
+    }
+
+    pub fn points(&self) -> u32 {
+        match self.matches() {
+            0 => 0,
+            matches => 1 << (matches - 1),
+        }
+    }
+}
+
+fn numbers(list: &str) -> Result<BTreeSet<u32>> {
+    list.split_whitespace()
+        .map(|number| {
+            number
+                .parse()
+                .with_context(|| format!("`{}` is not a number", number))
+        })
+        .collect()
+}
+
+impl FromStr for Scratchcard {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, numbers_part) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow!("expected `Card <id>:`"))?;
+        let id = name
+            .strip_prefix("Card")
+            .ok_or_else(|| anyhow!("expected `Card <id>:`"))?
+            .trim()
+            .parse()
+            .with_context(|| format!("`{}` has no card id", name))?;
+        let (winning, held) = numbers_part
+            .split_once('|')
+            .ok_or_else(|| anyhow!("expected `|` between the two lists of numbers"))?;
+
+        Ok(Scratchcard {
+            id,
+            winning: numbers(winning)?,
+            held: numbers(held)?,
+        })
+    }
+}
+
+/// Parses every non-blank line of `data` as a card.
+pub fn parse_cards(data: &str) -> Result<Vec<Scratchcard>> {
+    data.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            line.parse().map_err(|err: anyhow::Error| {
+                AocError::Parse {
+                    line: index + 1,
+                    message: format!("{:#}", err),
+                }
+                .into()
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn repeated_numbers_match_once() {
+        let card = "Card 7: 1 2 3 | 3 3 2 9".parse::<Scratchcard>().unwrap();
+
+        assert_eq!(card.id, 7);
+        assert_eq!(card.matches(), 2);
+        assert_eq!(card.points(), 2);
+    }
+}
@@ -0,0 +1,94 @@
+//! Where the copies won in part 2 come from.
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use aoc_common::{anyhow, Result};
+
+use crate::Scratchcard;
+
+/// A card after the cascade: how many instances of it there are and which
+/// cards won them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardCopies {
+    pub id: u32,
+    /// Instances held in the end, the original included.
+    pub copies: u64,
+    /// Copies won from each earlier card, by that card's id.
+    pub parents: BTreeMap<u32, u64>,
+}
+
+/// Plays out the cascade like [`crate::copy_counts`], also recording where
+/// each copy came from.
+pub fn cascade(cards: &[Scratchcard]) -> Result<Vec<CardCopies>> {
+    let mut result = cards
+        .iter()
+        .map(|card| CardCopies {
+            id: card.id,
+            copies: 1,
+            parents: BTreeMap::new(),
+        })
+        .collect::<Vec<_>>();
+
+    for (index, card) in cards.iter().enumerate() {
+        let copies = result[index].copies;
+        let last = (index + card.matches()).min(cards.len() - 1);
+
+        for child in &mut result[index + 1..=last] {
+            child.copies = child
+                .copies
+                .checked_add(copies)
+                .ok_or_else(|| anyhow!("copies of card {} overflow", child.id))?;
+            *child.parents.entry(card.id).or_default() += copies;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Renders the cascade as a Graphviz digraph, one edge per parent with the
+/// number of copies it contributed.
+pub fn to_dot(cascade: &[CardCopies]) -> String {
+    let mut dot = String::from("digraph scratchcards {\n    rankdir=LR;\n");
+
+    for card in cascade {
+        writeln!(
+            dot,
+            "    card{} [label=\"Card {}\\n{} copies\"];",
+            card.id, card.id, card.copies
+        )
+        .unwrap();
+    }
+
+    for card in cascade {
+        for (parent, copies) in &card.parents {
+            writeln!(
+                dot,
+                "    card{} -> card{} [label=\"{}\"];",
+                parent, card.id, copies
+            )
+            .unwrap();
+        }
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{copy_counts, parse_cards};
+
+    #[test]
+    fn example_cascade() {
+        let cards = parse_cards(include_str!("../data/example.txt")).unwrap();
+        let cascade = cascade(&cards).unwrap();
+
+        let copies = cascade.iter().map(|card| card.copies).collect::<Vec<_>>();
+
+        assert_eq!(copies, [1, 2, 4, 8, 14, 1]);
+        assert_eq!(copies, copy_counts(&cards).unwrap());
+        assert_eq!(cascade[4].parents, BTreeMap::from([(1, 1), (3, 4), (4, 8)]));
+        assert!(to_dot(&cascade).contains("card4 -> card5 [label=\"8\"];"));
+    }
+}
@@ -0,0 +1,68 @@
+use aoc_common::{anyhow, Answer, Result, Solver};
+
+mod card;
+pub mod cascade;
+
+pub use card::{parse_cards, Scratchcard};
+
+pub fn part1(data: &str) -> Result<Answer> {
+    let points = parse_cards(data)?
+        .iter()
+        .map(Scratchcard::points)
+        .sum::<u32>();
+
+    Ok(points.into())
+}
+
+/// How many instances of each card there are once every copy has been
+/// won. A card's count is final once the cards before it are done, so a
+/// single pass is enough.
+pub fn copy_counts(cards: &[Scratchcard]) -> Result<Vec<u64>> {
+    let mut copies = vec![1_u64; cards.len()];
+
+    for (index, card) in cards.iter().enumerate() {
+        let last = (index + card.matches()).min(cards.len() - 1);
+
+        for next in index + 1..=last {
+            copies[next] = copies[next]
+                .checked_add(copies[index])
+                .ok_or_else(|| anyhow!("copies of card {} overflow", cards[next].id))?;
+        }
+    }
+
+    Ok(copies)
+}
+
+/// Counts every scratchcard won, originals included.
+pub fn part2(data: &str) -> Result<Answer> {
+    let total = copy_counts(&parse_cards(data)?)?
+        .into_iter()
+        .try_fold(0_u64, u64::checked_add)
+        .ok_or_else(|| anyhow!("the total number of cards overflows"))?;
+
+    Ok(total.into())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn crate_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<usize>> {
+        Some(parse_cards(input).map(|cards| cards.len()))
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        part2(input)
+    }
+}
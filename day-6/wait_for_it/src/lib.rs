@@ -0,0 +1,52 @@
+use aoc_common::{anyhow, Answer, Result, Solver};
+
+mod race;
+mod races;
+
+pub use race::{BoatRace, RaceModel};
+pub use races::Races;
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let product = input
+        .parse::<Races>()?
+        .races()
+        .iter()
+        .map(BoatRace::max_beat_records)
+        .try_fold(1_i128, |product, count| {
+            product.checked_mul(i128::try_from(count).ok()?)
+        })
+        .ok_or_else(|| anyhow!("the product of the winning counts overflows"))?;
+
+    Ok(Answer::Number(product))
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let race = input.parse::<Races>()?.kerned()?;
+
+    // at most 2^64, always fits
+    Ok(Answer::Number(race.max_beat_records() as i128))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn crate_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<usize>> {
+        Some(input.parse::<Races>().map(|races| races.len()))
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -1,3 +1,10 @@
-fn main() {
-    println!("Hello, world!");
+use aoc_common::{load_input, print_answer, Result};
+
+fn main() -> Result<()> {
+    let input = load_input!()?;
+
+    print_answer(1, wait_for_it::part1(&input)?);
+    print_answer(2, wait_for_it::part2(&input)?);
+
+    Ok(())
 }
@@ -0,0 +1,349 @@
+use std::ops::RangeInclusive;
+
+/// How a boat behaves. The default is the puzzle's: every millisecond the
+/// button is held adds 1 mm/ms, there is no top speed, and a race is won by
+/// going at least 1 mm further than the record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaceModel {
+    /// Speed gained per millisecond held, in mm/ms.
+    pub acceleration: u64,
+    /// Speed before the button is pressed at all.
+    pub initial_speed: u64,
+    /// Holding longer than it takes to reach this speed gains nothing.
+    pub max_speed: Option<u64>,
+    /// How far past the record a boat has to go to count as a win.
+    pub margin: u64,
+}
+
+impl Default for RaceModel {
+    fn default() -> Self {
+        RaceModel {
+            acceleration: 1,
+            initial_speed: 0,
+            max_speed: None,
+            margin: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoatRace {
+    time: u64,
+    distance: u64,
+    model: RaceModel,
+}
+
+impl BoatRace {
+    pub fn new(time: u64, distance: u64) -> Self {
+        BoatRace {
+            time,
+            distance,
+            model: RaceModel::default(),
+        }
+    }
+
+    pub fn with_model(self, model: RaceModel) -> Self {
+        BoatRace { model, ..self }
+    }
+
+    fn speed(&self, hold: u64) -> u128 {
+        let RaceModel {
+            acceleration,
+            initial_speed,
+            max_speed,
+            ..
+        } = self.model;
+        let speed =
+            (u128::from(acceleration) * u128::from(hold)).saturating_add(u128::from(initial_speed));
+
+        max_speed.map_or(speed, |max| speed.min(u128::from(max)))
+    }
+
+    fn travelled(&self, hold: u64) -> u128 {
+        self.speed(hold)
+            .saturating_mul(u128::from(self.time - hold))
+    }
+
+    fn target(&self) -> u128 {
+        u128::from(self.distance) + u128::from(self.model.margin)
+    }
+
+    fn wins(&self, hold: u64) -> bool {
+        self.travelled(hold) >= self.target()
+    }
+
+    /// The hold times that beat the record, `None` if there are none.
+    ///
+    /// While the boat still accelerates the distance is a quadratic in the
+    /// hold time, solved with an exact integer square root; once it reaches
+    /// its top speed it is linear. Either way it takes constant time
+    /// whatever the size of the race.
+    pub fn winning_holds(&self) -> Option<RangeInclusive<u64>> {
+        let RaceModel {
+            acceleration,
+            initial_speed,
+            max_speed,
+            ..
+        } = self.model;
+
+        // first hold time at which the speed is capped, if within the race
+        let capped_from = match max_speed {
+            Some(max) if max <= initial_speed => Some(0),
+            Some(max) if acceleration > 0 => {
+                Some((max - initial_speed).div_ceil(acceleration)).filter(|&hold| hold <= self.time)
+            }
+            _ => None,
+        };
+
+        let accelerating = match capped_from {
+            Some(0) => None,
+            Some(hold) => Some(0..=hold - 1),
+            None => Some(0..=self.time),
+        };
+
+        let before = accelerating.and_then(|holds| match acceleration {
+            0 => self.constant_speed(initial_speed, holds),
+            _ => self.quadratic(holds),
+        });
+        let after =
+            capped_from.and_then(|hold| self.constant_speed(max_speed.unwrap(), hold..=self.time));
+
+        // the winners before and after the cap always touch
+        match (before, after) {
+            (Some(before), Some(after)) => Some(*before.start()..=*after.end()),
+            (before, after) => before.or(after),
+        }
+    }
+
+    /// Winners among `holds` when the boat always ends up at `speed`.
+    fn constant_speed(
+        &self,
+        speed: u64,
+        holds: RangeInclusive<u64>,
+    ) -> Option<RangeInclusive<u64>> {
+        if speed == 0 {
+            return (self.target() == 0).then_some(holds);
+        }
+
+        // time - hold must be at least target / speed
+        let needed = self.target().div_ceil(u128::from(speed));
+        let last = u128::from(self.time).checked_sub(needed)? as u64;
+
+        Some(*holds.start()..=last.min(*holds.end())).filter(|holds| !holds.is_empty())
+    }
+
+    /// Winners among `holds` while the boat is still accelerating.
+    fn quadratic(&self, holds: RangeInclusive<u64>) -> Option<RangeInclusive<u64>> {
+        let RaceModel {
+            acceleration,
+            initial_speed,
+            ..
+        } = self.model;
+
+        // (v + a·h)(t - h) >= target  <=>  a·h² - (a·t - v)·h - (v·t - target) <= 0
+        let roots = (|| {
+            let (a, v) = (i128::from(acceleration), i128::from(initial_speed));
+            let (t, target) = (i128::from(self.time), i128::try_from(self.target()).ok()?);
+
+            let b = a.checked_mul(t)?.checked_sub(v)?;
+            let c = v.checked_mul(t)?.checked_sub(target)?;
+            let discriminant = b
+                .checked_mul(b)?
+                .checked_add(a.checked_mul(c)?.checked_mul(4)?)?;
+            if discriminant < 0 {
+                return Some(None);
+            }
+
+            let root = discriminant.isqrt();
+            Some(Some((
+                (b - root).div_euclid(2 * a),
+                (b + root).div_euclid(2 * a) + 1,
+            )))
+        })();
+
+        match roots {
+            Some(Some((first, last))) => self.refine(first, last, holds),
+            Some(None) => None,
+            None => self.search(holds),
+        }
+    }
+
+    /// Turns estimates within a step of the real bounds into exact ones.
+    fn refine(
+        &self,
+        first: i128,
+        last: i128,
+        holds: RangeInclusive<u64>,
+    ) -> Option<RangeInclusive<u64>> {
+        let (start, end) = (*holds.start(), *holds.end());
+        let clamp = |hold: i128| hold.clamp(i128::from(start), i128::from(end)) as u64;
+        let (mut first, mut last) = (clamp(first), clamp(last));
+
+        while first > start && self.wins(first - 1) {
+            first -= 1;
+        }
+        while !self.wins(first) {
+            if first >= last {
+                return None;
+            }
+            first += 1;
+        }
+        while last < end && self.wins(last + 1) {
+            last += 1;
+        }
+        while !self.wins(last) {
+            last -= 1;
+        }
+
+        Some(first..=last)
+    }
+
+    /// Binary searches for the bounds, for races too large for the roots to
+    /// be computed exactly.
+    fn search(&self, holds: RangeInclusive<u64>) -> Option<RangeInclusive<u64>> {
+        let (mut low, mut high) = (*holds.start(), *holds.end());
+
+        // the distance rises then falls, find where it peaks
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.travelled(mid) < self.travelled(mid + 1) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        let peak = low;
+        if !self.wins(peak) {
+            return None;
+        }
+
+        let (start, end) = (*holds.start(), *holds.end());
+        let first = partition_point(start, peak, |hold| !self.wins(hold));
+        let last = match self.wins(end) {
+            true => end,
+            false => partition_point(peak, end, |hold| self.wins(hold)) - 1,
+        };
+
+        Some(first..=last)
+    }
+
+    /// How many hold times beat the record. A `u128`, as every hold time of
+    /// the longest race is one more than a `u64` holds.
+    pub fn max_beat_records(&self) -> u128 {
+        self.winning_holds()
+            .map_or(0, |holds| u128::from(holds.end() - holds.start()) + 1)
+    }
+
+    /// Tries every hold time, kept to check [`Self::max_beat_records`].
+    pub fn brute_force_beat_records(&self) -> u128 {
+        (0..=self.time).filter(|&hold| self.wins(hold)).count() as u128
+    }
+}
+
+/// First value in `low..=high` for which `pred` is false. `pred` has to
+/// hold for a prefix of the range only, and not for `high`.
+fn partition_point(mut low: u64, mut high: u64, pred: impl Fn(u64) -> bool) -> u64 {
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn closed_form_matches_brute_force() {
+        for time in 0..60 {
+            for distance in 0..(time * time / 4 + 2) {
+                let race = BoatRace::new(time, distance);
+                assert_eq!(
+                    race.max_beat_records(),
+                    race.brute_force_beat_records(),
+                    "{:?}",
+                    race
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn huge_races_do_not_overflow() {
+        let race = BoatRace::new(u64::MAX, u64::MAX);
+        let holds = race.winning_holds().unwrap();
+
+        assert_eq!(holds.start() + holds.end(), u64::MAX);
+        assert!(race.wins(*holds.start()) && !race.wins(holds.start() - 1));
+
+        let always = RaceModel {
+            acceleration: 0,
+            initial_speed: 1,
+            max_speed: None,
+            margin: 0,
+        };
+        assert_eq!(
+            BoatRace::new(u64::MAX, 0)
+                .with_model(always)
+                .max_beat_records(),
+            u128::from(u64::MAX) + 1
+        );
+
+        // too large for the roots, so the bounds are searched for
+        let steep = BoatRace::new(u64::MAX, 0).with_model(RaceModel {
+            acceleration: u64::MAX,
+            margin: 0,
+            ..RaceModel::default()
+        });
+        assert_eq!(steep.winning_holds(), Some(0..=u64::MAX));
+    }
+
+    #[test]
+    fn other_models_match_brute_force() {
+        for acceleration in 0..4 {
+            for initial_speed in 0..4 {
+                for max_speed in [None, Some(0), Some(3), Some(7)] {
+                    for margin in [0, 1, 5] {
+                        let model = RaceModel {
+                            acceleration,
+                            initial_speed,
+                            max_speed,
+                            margin,
+                        };
+
+                        for time in 0..25 {
+                            for distance in (0..80).step_by(3) {
+                                let race = BoatRace::new(time, distance).with_model(model);
+                                assert_eq!(
+                                    race.max_beat_records(),
+                                    race.brute_force_beat_records(),
+                                    "{:?}",
+                                    race
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn search_agrees_with_the_roots() {
+        let race = BoatRace::new(71530, 940200).with_model(RaceModel {
+            acceleration: 3,
+            initial_speed: 2,
+            max_speed: None,
+            margin: 10,
+        });
+
+        assert_eq!(race.search(0..=71530), race.quadratic(0..=71530));
+    }
+}
@@ -0,0 +1,147 @@
+use std::str::FromStr;
+
+use aoc_common::{AocError, Result};
+
+use crate::BoatRace;
+
+/// The puzzle sheet: a `Time:` row and a `Distance:` row with one column
+/// per race. The digits are kept as written, since part 2 reads each row
+/// as one number.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Races {
+    times: Vec<String>,
+    distances: Vec<String>,
+}
+
+fn parse_error(line: usize, message: String) -> anyhow::Error {
+    AocError::Parse { line, message }.into()
+}
+
+/// The numbers of one row after its `header`, all of them checked.
+fn parse_row(line: usize, row: &str, header: &str) -> Result<Vec<String>> {
+    let Some(numbers) = row.strip_prefix(header) else {
+        let found = row.split_whitespace().next().unwrap_or_default();
+        return Err(parse_error(
+            line,
+            format!("expected `{}`, found `{}`", header, found),
+        ));
+    };
+
+    let mut columns = Vec::new();
+    let mut rest = numbers;
+
+    while let Some(start) = rest.find(|c: char| !c.is_whitespace()) {
+        let token = rest[start..].split_whitespace().next().unwrap();
+        let column = row.len() - rest.len() + start + 1;
+
+        if token.parse::<u64>().is_err() {
+            return Err(parse_error(
+                line,
+                format!("`{}` at column {} is not a number", token, column),
+            ));
+        }
+
+        columns.push(token.to_string());
+        rest = &rest[start + token.len()..];
+    }
+
+    if columns.is_empty() {
+        return Err(parse_error(line, format!("no numbers after `{}`", header)));
+    }
+
+    Ok(columns)
+}
+
+impl FromStr for Races {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut rows = s
+            .lines()
+            .enumerate()
+            .map(|(index, row)| (index + 1, row.trim_end()))
+            .filter(|(_, row)| !row.is_empty());
+
+        let (line, row) = rows
+            .next()
+            .ok_or_else(|| parse_error(1, "expected `Time:`, found nothing".to_string()))?;
+        let times = parse_row(line, row, "Time:")?;
+
+        let (line, row) = rows.next().ok_or_else(|| {
+            parse_error(line + 1, "expected `Distance:`, found nothing".to_string())
+        })?;
+        let distances = parse_row(line, row, "Distance:")?;
+
+        if distances.len() != times.len() {
+            return Err(parse_error(
+                line,
+                format!("{} distances for {} times", distances.len(), times.len()),
+            ));
+        }
+
+        if let Some((line, row)) = rows.next() {
+            return Err(parse_error(line, format!("unexpected `{}`", row)));
+        }
+
+        Ok(Races { times, distances })
+    }
+}
+
+impl Races {
+    pub fn len(&self) -> usize {
+        self.times.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.times.is_empty()
+    }
+
+    /// Every column as its own race.
+    pub fn races(&self) -> Vec<BoatRace> {
+        // every token was checked to be a u64 while parsing
+        self.times
+            .iter()
+            .zip(&self.distances)
+            .map(|(time, distance)| BoatRace::new(time.parse().unwrap(), distance.parse().unwrap()))
+            .collect()
+    }
+
+    /// The single race part 2 finds once the spaces are ignored.
+    pub fn kerned(&self) -> Result<BoatRace> {
+        let join = |row: &[String], name: &str| {
+            row.concat()
+                .parse::<u64>()
+                .map_err(|_| anyhow::anyhow!("the {} row is too large for one race", name))
+        };
+
+        Ok(BoatRace::new(
+            join(&self.times, "time")?,
+            join(&self.distances, "distance")?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn error(input: &str) -> String {
+        input.parse::<Races>().unwrap_err().to_string()
+    }
+
+    #[test]
+    fn offending_tokens_are_reported() {
+        assert_eq!(
+            error("Time: 7 15 3x\nDistance: 9 40 200\n"),
+            "parse error on line 1: `3x` at column 12 is not a number"
+        );
+        assert_eq!(
+            error("Time: 7 15 30\nDistance: 9 40\n"),
+            "parse error on line 2: 2 distances for 3 times"
+        );
+        assert_eq!(
+            error("Time: 7\nSpeed: 9\n"),
+            "parse error on line 2: expected `Distance:`, found `Speed:`"
+        );
+    }
+}
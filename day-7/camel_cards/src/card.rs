@@ -0,0 +1,184 @@
+use std::cmp::Ordering;
+
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+
+use crate::Rules;
+
+/// How many cards make a hand.
+pub const HAND_SIZE: usize = 5;
+
+#[derive(Debug, PartialEq, PartialOrd, Hash, Eq, Ord, Clone, Copy)]
+pub enum Card {
+    H2 = 2,
+    H3,
+    H4,
+    H5,
+    H6,
+    H7,
+    H8,
+    H9,
+    T,
+    J,
+    Q,
+    K,
+    A,
+}
+
+impl TryFrom<char> for Card {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            'A' => Ok(Card::A),
+            'K' => Ok(Card::K),
+            'Q' => Ok(Card::Q),
+            'J' => Ok(Card::J),
+            'T' => Ok(Card::T),
+            '9' => Ok(Card::H9),
+            '8' => Ok(Card::H8),
+            '7' => Ok(Card::H7),
+            '6' => Ok(Card::H6),
+            '5' => Ok(Card::H5),
+            '4' => Ok(Card::H4),
+            '3' => Ok(Card::H3),
+            '2' => Ok(Card::H2),
+            _ => Err(anyhow!("`{}` is not a card", value)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Eq, Ord)]
+pub enum HandKind {
+    High,        // 5 - different
+    OnePair,     // 2 - same, 3 different
+    TwoPair,     // 2 - same, 2 - same, 1 - different
+    ThreeOfKind, // 3- same, 2 - different
+    FullHouse,   // 3 - same, 2 - same
+    FourKind,    // 4 - same, 1 - different
+    FiveKind,    // 5 - same
+}
+
+impl HandKind {
+    /// The kind of `cards` under `rules`. Jokers join whichever card the
+    /// hand holds most of, as that always makes the strongest hand.
+    pub fn classify(cards: &[Card], rules: Rules) -> Result<HandKind> {
+        if cards.len() != HAND_SIZE {
+            bail!("a hand has {} cards, not {}", HAND_SIZE, cards.len());
+        }
+
+        let is_joker = |card: &&Card| rules == Rules::Joker && **card == Card::J;
+        let jokers = cards.iter().filter(is_joker).count();
+
+        let mut counts = cards
+            .iter()
+            .filter(|card| !is_joker(card))
+            .counts()
+            .into_values()
+            .sorted()
+            .rev()
+            .collect_vec();
+        match counts.first_mut() {
+            Some(most) => *most += jokers,
+            None => counts.push(jokers),
+        }
+
+        Ok(match counts[..] {
+            [5] => HandKind::FiveKind,
+            [4, 1] => HandKind::FourKind,
+            [3, 2] => HandKind::FullHouse,
+            [3, 1, 1] => HandKind::ThreeOfKind,
+            [2, 2, 1] => HandKind::TwoPair,
+            [2, 1, 1, 1] => HandKind::OnePair,
+            _ => HandKind::High,
+        })
+    }
+}
+
+/// The cards of a hand and the rules it is played under.
+///
+/// Hands are ordered by kind first, then card by card. Only hands played
+/// under the same rules are meaningfully compared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    cards: Vec<Card>,
+    rules: Rules,
+    kind: HandKind,
+}
+
+impl Hand {
+    /// Fails unless there are exactly five cards.
+    pub fn new(cards: Vec<Card>, rules: Rules) -> Result<Self> {
+        let kind = HandKind::classify(&cards, rules)?;
+
+        Ok(Hand { cards, rules, kind })
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn kind(&self) -> HandKind {
+        self.kind
+    }
+
+    fn strengths(&self) -> impl Iterator<Item = u8> + '_ {
+        self.cards.iter().map(|&card| self.rules.strength(card))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.kind
+            .cmp(&other.kind)
+            .then_with(|| self.strengths().cmp(other.strengths()))
+            .then_with(|| self.rules.cmp(&other.rules))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Card::*;
+
+    #[test]
+    fn kinds() {
+        let kind = |cards: [Card; 5]| HandKind::classify(&cards, Rules::Standard).unwrap();
+
+        assert_eq!(kind([A, A, A, A, A]), HandKind::FiveKind);
+        assert_eq!(kind([A, A, A, A, Q]), HandKind::FourKind);
+        assert_eq!(kind([A, A, A, Q, Q]), HandKind::FullHouse);
+        assert_eq!(kind([A, A, Q, A, Q]), HandKind::FullHouse);
+        assert_eq!(kind([A, A, A, T, Q]), HandKind::ThreeOfKind);
+        assert_eq!(kind([T, A, A, A, Q]), HandKind::ThreeOfKind);
+    }
+
+    #[test]
+    fn jokers_join_the_largest_group() {
+        let kind = |cards: [Card; 5]| HandKind::classify(&cards, Rules::Joker).unwrap();
+
+        assert_eq!(kind([J, J, J, J, J]), HandKind::FiveKind);
+        assert_eq!(kind([T, H5, H5, J, H5]), HandKind::FourKind);
+        assert_eq!(kind([K, T, J, J, T]), HandKind::FourKind);
+        assert_eq!(kind([H2, H3, J, H4, H5]), HandKind::OnePair);
+        assert!(
+            Hand::new(vec![J, K, K, K, H2], Rules::Joker).unwrap()
+                < Hand::new(vec![Q, Q, Q, Q, H2], Rules::Joker).unwrap()
+        );
+    }
+
+    #[test]
+    fn identical_hands_are_equal() {
+        let hand = Hand::new(vec![A, K, Q, J, T], Rules::Standard).unwrap();
+
+        assert_eq!(hand.cmp(&hand.clone()), Ordering::Equal);
+        assert!(Hand::new(vec![A, K, Q, J], Rules::Standard).is_err());
+        assert!(HandKind::classify(&[A; 6], Rules::Joker).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::{anyhow, Result};
+use aoc_common::AocError;
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::{complete::one_of, is_digit},
+    combinator::{all_consuming, map_res},
+    multi::many1,
+    sequence::separated_pair,
+    Finish, IResult,
+};
+
+use crate::{Card, Hand, Rules};
+
+#[derive(Debug, PartialEq, PartialOrd)]
+pub struct Game {
+    pub hand: Hand,
+    pub bid: u32,
+}
+
+fn parse_card(input: &str) -> IResult<&str, Card> {
+    map_res(one_of("AKQJT98765432"), Card::try_from)(input)
+}
+
+fn parse_cards(input: &str) -> IResult<&str, Vec<Card>> {
+    many1(parse_card)(input)
+}
+
+fn parse_bid(input: &str) -> IResult<&str, u32> {
+    map_res(take_while1(|u| is_digit(u as u8)), str::parse)(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, (Vec<Card>, u32)> {
+    separated_pair(parse_cards, tag(" "), parse_bid)(input)
+}
+
+fn parse_game(line: &str, rules: Rules) -> Result<Game> {
+    let (_, (cards, bid)) = all_consuming(parse_line)(line)
+        .finish()
+        .map_err(|err| anyhow!("expected cards and a bid at `{}`", err.input))?;
+
+    Ok(Game {
+        hand: Hand::new(cards, rules)?,
+        bid,
+    })
+}
+
+/// Parses every non-blank line of `data` as a game.
+pub fn parse_games(data: &str, rules: Rules) -> Result<Vec<Game>> {
+    data.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            parse_game(line, rules).map_err(|err| {
+                AocError::Parse {
+                    line: index + 1,
+                    message: format!("{:#}", err),
+                }
+                .into()
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_card() {
+        assert_eq!(parse_card("A"), Ok(("", Card::A)));
+    }
+
+    #[test]
+    fn test_parse_cards() {
+        assert_eq!(
+            parse_cards("AAA22"),
+            Ok(("", vec![Card::A, Card::A, Card::A, Card::H2, Card::H2]))
+        );
+    }
+
+    #[test]
+    fn bad_lines_are_reported() {
+        let error = parse_games("32T3K 765\nKK67 28\n", Rules::Standard).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "parse error on line 2: a hand has 5 cards, not 4"
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use aoc_common::{Answer, Result, Solver};
+use itertools::Itertools;
+
+mod card;
+mod grammar;
+
+pub use card::{Card, Hand, HandKind, HAND_SIZE};
+pub use grammar::{parse_games, Game};
+
+/// Which rules hands are scored under: part 1's, or part 2's where `J` is a
+/// joker that counts as whatever card makes the hand strongest but is the
+/// weakest card on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Rules {
+    #[default]
+    Standard,
+    Joker,
+}
+
+impl Rules {
+    /// How strong `card` is when breaking ties between hands of a kind.
+    pub fn strength(self, card: Card) -> u8 {
+        match (self, card) {
+            (Rules::Joker, Card::J) => 1,
+            _ => card as u8,
+        }
+    }
+}
+
+/// Each bid multiplied by the rank of its hand, weakest hand first.
+pub fn total_winnings(data: &str, rules: Rules) -> Result<usize> {
+    Ok(parse_games(data, rules)?
+        .iter()
+        .sorted_by(|a, b| a.hand.cmp(&b.hand))
+        .enumerate()
+        .map(|(index, game)| (index + 1) * game.bid as usize)
+        .sum::<usize>())
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    Ok(total_winnings(input, Rules::Standard)?.into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    Ok(total_winnings(input, Rules::Joker)?.into())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn crate_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<usize>> {
+        Some(parse_games(input, Rules::Standard).map(|games| games.len()))
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn example() {
+        let example = include_str!("../data/example.txt");
+
+        assert_eq!(total_winnings(example, Rules::Standard).unwrap(), 6440);
+        assert_eq!(total_winnings(example, Rules::Joker).unwrap(), 5905);
+    }
+}
@@ -0,0 +1,112 @@
+use aoc_common::{Answer, AocError, Result, Solver};
+use itertools::Itertools;
+
+fn differences(list: &[i32]) -> Vec<i32> {
+    list.iter()
+        .tuple_windows()
+        .map(|(a, b)| b - a)
+        .collect_vec()
+}
+
+fn is_all_zero(list: &[i32]) -> bool {
+    list.iter().all(|&x| x == 0)
+}
+
+fn next_value(list: &[i32]) -> i32 {
+    if list.is_empty() || is_all_zero(list) {
+        return 0;
+    }
+
+    list.last().unwrap() + next_value(&differences(list))
+}
+
+fn previous_value(list: &[i32]) -> i32 {
+    if list.is_empty() || is_all_zero(list) {
+        return 0;
+    }
+
+    list.first().unwrap() - previous_value(&differences(list))
+}
+
+fn parse_line(line: &str) -> std::result::Result<Vec<i32>, String> {
+    line.split_whitespace()
+        .map(|a| {
+            a.parse::<i32>()
+                .map_err(|_| format!("`{}` is not a number", a))
+        })
+        .collect()
+}
+
+/// Parses every non-blank line of `input` as a history of readings.
+fn parse_histories(input: &str) -> Result<Vec<Vec<i32>>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            parse_line(line).map_err(|message| {
+                AocError::Parse {
+                    line: index + 1,
+                    message,
+                }
+                .into()
+            })
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let sum = parse_histories(input)?
+        .iter()
+        .map(|history| next_value(history))
+        .sum::<i32>();
+
+    Ok(sum.into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let sum = parse_histories(input)?
+        .iter()
+        .map(|history| previous_value(history))
+        .sum::<i32>();
+
+    Ok(sum.into())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn crate_dir(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<usize>> {
+        Some(parse_histories(input).map(|histories| histories.len()))
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn spacing_is_free_but_numbers_are_not() {
+        assert_eq!(part1("0 3  6\n").unwrap(), part1("0 3 6\n").unwrap());
+        assert_eq!(
+            part1("0 3 6\n1 x\n").unwrap_err().to_string(),
+            "parse error on line 2: `x` is not a number"
+        );
+    }
+}